@@ -1,49 +1,139 @@
 #![allow(warnings)]
 extern crate proc_macro;
 
-use if_chain::*;
 use proc_macro::TokenStream;
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::*;
 use std::cmp::Ord;
 use std::cmp::{max, min};
 use syn;
-use syn::visit::Visit;
-use syn::{parse_macro_input, parse_quote};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, parse_quote, parse_quote_spanned};
 use syn::{Attribute, Lit, Meta, NestedMeta, Visibility};
 
-#[proc_macro_derive(StableABI)]
+#[proc_macro_derive(StableABI, attributes(stable_abi))]
 pub fn stable_abi(input: TokenStream) -> TokenStream {
     use syn::DeriveInput;
     let DeriveInput {
       ident,
       generics,
+      attrs,
       ..
     } = parse_macro_input!(input as DeriveInput);
 
-    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let bounds = match AbiBounds::parse(&attrs) {
+        Ok(bounds) => bounds,
+        Err(error) => return error.to_compile_error().into(),
+    };
+
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+
+    // `Self` must be transmutable into its `into` bound, and from its `from`
+    // bound. For generic types, this is checked for each instantiation.
+    let mut into_generics = generics.clone();
+    let mut from_generics = generics.clone();
+
+    if let Some(into) = &bounds.into {
+        into_generics.make_where_clause().predicates.push(parse_quote_spanned! {into.span()=>
+          #into: typic::stability::AbiBound<#ident #ty_generics>
+        });
+    }
+
+    if let Some(from) = &bounds.from {
+        from_generics.make_where_clause().predicates.push(parse_quote_spanned! {from.span()=>
+          #ident #ty_generics: typic::stability::AbiBound<#from>
+        });
+    }
+
+    let into = bounds.into.as_ref().map_or(quote!(#ident #ty_generics), ToTokens::to_token_stream);
+    let from = bounds.from.as_ref().map_or(quote!(#ident #ty_generics), ToTokens::to_token_stream);
+
+    // The layout of a generic type may depend on its parameters.
+    if !generics.params.is_empty() {
+        into_generics.make_where_clause().predicates.push(parse_quote! {
+          #into: typic::layout::Layout
+        });
+        from_generics.make_where_clause().predicates.push(parse_quote! {
+          #from: typic::layout::Layout
+        });
+    }
+
+    let into_where_clause = &into_generics.where_clause;
+    let from_where_clause = &from_generics.where_clause;
+
     (quote! {
         unsafe impl #impl_generics typic::stability::TransmutableFrom
-        for #ident #ty_generics #where_clause
+        for #ident #ty_generics #from_where_clause
         {
-            type Type = Self;
+            type Type = #from;
         }
 
         unsafe impl #impl_generics typic::stability::TransmutableInto
-        for #ident #ty_generics #where_clause
+        for #ident #ty_generics #into_where_clause
         {
-            type Type = Self;
+            type Type = #into;
         }
-    
     }).into()
 }
 
+/// The ABI bounds declared with `#[stable_abi(into = "...", from = "...")]`.
+#[derive(Default)]
+struct AbiBounds {
+    into: Option<syn::Type>,
+    from: Option<syn::Type>,
+}
+
+impl AbiBounds {
+    /// Parse the `#[stable_abi(...)]` attributes among `attrs`.
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut bounds = AbiBounds::default();
+        for attr in attrs {
+            if !attr.path.is_ident("stable_abi") {
+                continue;
+            }
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                meta => return Err(syn::Error::new_spanned(
+                    meta,
+                    "malformed `stable_abi` attribute; expected \
+                     `#[stable_abi(into = \"...\", from = \"...\")]`",
+                )),
+            };
+            for bound in &list.nested {
+                let (slot, ty) = match bound {
+                    NestedMeta::Meta(Meta::NameValue(syn::MetaNameValue {
+                        path,
+                        lit: Lit::Str(ty),
+                        ..
+                    })) => match path.get_ident().map(ToString::to_string).as_deref() {
+                        Some("into") => (&mut bounds.into, ty),
+                        Some("from") => (&mut bounds.from, ty),
+                        _ => return Err(syn::Error::new_spanned(
+                            path,
+                            "unrecognized ABI bound; expected `into` or `from`",
+                        )),
+                    },
+                    _ => return Err(syn::Error::new_spanned(
+                        bound,
+                        "malformed ABI bound; expected `into = \"...\"` or `from = \"...\"`",
+                    )),
+                };
+                if slot.is_some() {
+                    return Err(syn::Error::new_spanned(bound, "duplicate ABI bound"));
+                }
+                *slot = Some(ty.parse()?);
+            }
+        }
+        Ok(bounds)
+    }
+}
+
 #[proc_macro_attribute]
 pub fn typicrepr(_args: TokenStream, input: TokenStream) -> TokenStream {
     repr(_args, input)
 }
 
-fn impl_struct(definition: syn::ItemStruct) -> TokenStream {
+fn impl_struct(definition: syn::ItemStruct, repr: Repr) -> TokenStream {
     let name = &definition.ident;
     let attrs = &definition.attrs;
     let generics = &definition.generics;
@@ -58,34 +148,6 @@ fn impl_struct(definition: syn::ItemStruct) -> TokenStream {
         }
     });
 
-    let mut repr = Repr::default();
-    attrs
-        .into_iter()
-        .for_each(|attr| repr.visit_attribute(attr));
-
-    if let Some(Method::Transparent) = repr.method {
-        return (quote! {
-          #definition
-
-          impl #impl_generics typic::internal::Type
-          for #name #ty_generics #where_clause
-          {
-            #[doc(hidden)]
-            type ReprAlign =
-                <#name #ty_generics as typic::internal::Type>::ReprAlign;
-
-            #[doc(hidden)]
-            type ReprPacked =
-                <#name #ty_generics as typic::internal::Type>::ReprPacked;
-
-            #[doc(hidden)]
-            type HighLevel =
-                <#name #ty_generics as typic::internal::Type>::HighLevel;
-          }
-        })
-        .into();
-    }
-
     let repr_align = repr
         .align
         .map(|n| format_ident!("U{}", n))
@@ -96,8 +158,23 @@ fn impl_struct(definition: syn::ItemStruct) -> TokenStream {
         .map(|n| format_ident!("U{}", n))
         .unwrap_or(format_ident!("MaxAlign"));
 
-    // no repr
-    if let None = repr.method {
+    // no repr: the layout is opaque
+    if let None | Some(Method::Rust) = repr.method {
+        // The size of a generic type cannot be named in a const generic
+        // argument, so only non-generic types have a known extent.
+        let extent = if generics.params.is_empty() {
+            quote! {
+              impl typic::internal::Extent for #name {
+                #[doc(hidden)] type Size =
+                    typic::internal::U<{ core::mem::size_of::<#name>() }>;
+                #[doc(hidden)] type Align =
+                    typic::internal::U<{ core::mem::align_of::<#name>() }>;
+              }
+            }
+        } else {
+            quote!()
+        };
+
         return (quote! {
           #definition
 
@@ -106,26 +183,120 @@ fn impl_struct(definition: syn::ItemStruct) -> TokenStream {
           {
             #[doc(hidden)] type ReprAlign = typic::internal::#repr_align;
             #[doc(hidden)] type ReprPacked = typic::internal::#repr_packed;
-            #[doc(hidden)] type HighLevel = Self;
+            #[doc(hidden)] type HighLevel = typic::internal::Opaque<Self>;
           }
+
+          #extent
         })
         .into();
     }
 
-    // otherwise, it's a C repr
-    assert_eq!(repr.method, Some(Method::C));
+    // A `repr(transparent)` struct is laid out as a `repr(C)` struct: rustc
+    // requires its fields but one to be 1-aligned ZSTs, so that struct has
+    // the layout, and the visibility, of that one field. (rustc may place the
+    // ZSTs at any offset, so their offsets are not checked.)
+    let fields_checked = match repr.method {
+        Some(Method::Transparent) => None,
+        _ => Some(&definition.fields),
+    };
+
+    let fields = fields(&definition.fields, |field| {
+        if let Visibility::Public(_) = field.vis {
+            format_ident!("Public")
+        } else {
+            format_ident!("Private")
+        }
+    });
+
+    let checks = if repr.checked {
+        layout_checks(name, generics, fields_checked)
+    } else {
+        quote!()
+    };
+
+    (quote! {
+      #definition
+
+      impl #impl_generics typic::internal::Type
+      for #name #ty_generics #where_clause
+      {
+        #[doc(hidden)] type ReprAlign = typic::internal::#repr_align;
+        #[doc(hidden)] type ReprPacked = typic::internal::#repr_packed;
+        #[doc(hidden)] type HighLevel = #fields;
+      }
+
+      #checks
+    })
+    .into()
+}
 
-    let fields = definition
-        .fields
+/// Const assertions that the size and alignment typic computes for the type
+/// `name` are those computed by rustc, as are the offsets of its `fields`.
+fn layout_checks(
+    name: &syn::Ident,
+    generics: &syn::Generics,
+    fields: Option<&syn::Fields>,
+) -> TokenStream2 {
+    // The layout of a type with type or const parameters cannot be named in a
+    // const item. Lifetimes, however, do not affect layout.
+    if generics.type_params().next().is_some() || generics.const_params().next().is_some() {
+        return quote!();
+    }
+
+    let ty = if generics.params.is_empty() {
+        quote!(#name)
+    } else {
+        let lifetimes = generics.lifetimes().map(|_| quote!('static));
+        quote!(#name<#(#lifetimes),*>)
+    };
+
+    let size = format!("typic computes a size for `{}` that differs from rustc's", name);
+    let align = format!("typic computes an alignment for `{}` that differs from rustc's", name);
+
+    let offsets = fields.into_iter().flatten().enumerate().map(|(index, field)| {
+        let member = match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => syn::Index::from(index).into_token_stream(),
+        };
+        let message = format!(
+            "typic computes an offset for `{}.{}` that differs from rustc's",
+            name, member,
+        );
+        quote! {
+          assert!(
+            <typic::private::layout::FieldOffsetOf<#ty, typic::internal::U<#index>>
+              as typic::private::num::Unsigned>::USIZE
+              == core::mem::offset_of!(#ty, #member),
+            #message,
+          );
+        }
+    });
+
+    quote! {
+      const _: () = {
+        assert!(
+          <typic::layout::SizeOf<#ty> as typic::private::num::Unsigned>::USIZE
+            == core::mem::size_of::<#ty>(),
+          #size,
+        );
+        assert!(
+          <typic::layout::AlignOf<#ty> as typic::private::num::Unsigned>::USIZE
+            == core::mem::align_of::<#ty>(),
+          #align,
+        );
+        #(#offsets)*
+      };
+    }
+}
+
+/// The high-level product of `fields`, whose visibilities are given by `vis`.
+fn fields(fields: &syn::Fields, vis: impl Fn(&syn::Field) -> syn::Ident) -> TokenStream2 {
+    fields
         .iter()
         .rfold(
             quote! {typic::internal::PNil},
             |rest, field| {
-              let vis = if let Visibility::Public(_) = field.vis {
-                format_ident!("Public")
-              } else {
-                format_ident!("Private")
-              };
+              let vis = vis(field);
               let field = field.ty.clone();
               quote! {
                 typic::internal::PCons<
@@ -135,7 +306,152 @@ fn impl_struct(definition: syn::ItemStruct) -> TokenStream {
                   #rest>
               }
             },
+        )
+}
+
+fn impl_enum(definition: syn::ItemEnum, repr: Repr) -> TokenStream {
+    let name = &definition.ident;
+    let attrs = &definition.attrs;
+    let generics = &definition.generics;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let error = |tokens: &dyn ToTokens, message: &str| -> TokenStream {
+        emit_error(&definition, syn::Error::new_spanned(tokens, message))
+    };
+
+    // The integer type of the discriminant. A `repr(C)` enum's discriminant
+    // is a C `int`.
+    let size = match (repr.method, repr.size) {
+        (_, Some(size)) => size,
+        (Some(Method::C), None) => Size::I32,
+        _ => return error(
+            name,
+            "typic requires enums to have a `C` or primitive representation",
+        ),
+    };
+
+    let mut discriminants = Vec::new();
+    let mut next: u128 = 0;
+
+    for variant in &definition.variants {
+        let value = match &variant.discriminant {
+            None => next,
+            Some((_, expr)) => match discriminant(expr) {
+                Some(value) => value,
+                None => return error(
+                    expr,
+                    "typic requires enum discriminants to be integer literals",
+                ),
+            },
+        };
+
+        discriminants.push((value, fields(&variant.fields, |_| format_ident!("Public"))));
+        next = value.wrapping_add(1);
+    }
+
+    let repr_align = repr
+        .align
+        .map(|n| format_ident!("U{}", n))
+        .unwrap_or(format_ident!("MinAlign"));
+
+    let repr_packed = repr
+        .packed
+        .map(|n| format_ident!("U{}", n))
+        .unwrap_or(format_ident!("MaxAlign"));
+
+    let tag = size.ident();
+
+    // The bit pattern of a pointer-sized discriminant depends on the width of
+    // pointers on the target, so one is given for each width.
+    let value = |value: u128| match size.bits() {
+        Some(bits) => uint(truncate(value, bits)),
+        None => {
+            let [v16, v32, v64] = [16, 32, 64].map(|bits| uint(truncate(value, bits)));
+            quote!(typic::internal::PointerSized<#v16, #v32, #v64>)
+        }
+    };
+
+    let checks = if repr.checked {
+        layout_checks(name, generics, None)
+    } else {
+        quote!()
+    };
+
+    let layout = if let Some(Method::C) = repr.method {
+        format_ident!("ReprC")
+    } else {
+        format_ident!("ReprInt")
+    };
+
+    let variants = discriminants.iter().rfold(
+        quote! {typic::internal::CNil},
+        |rest, (discriminant, fields)| {
+            let value = value(*discriminant);
+            quote! {
+              typic::internal::CCons<
+                typic::internal::Variant<
+                  typic::internal::Discriminant<#tag, #value>,
+                  #fields>,
+                #rest>
+            }
+        },
+    );
+
+    (quote! {
+      #definition
+
+      impl #impl_generics typic::internal::Type
+      for #name #ty_generics #where_clause
+      {
+        #[doc(hidden)] type ReprAlign = typic::internal::#repr_align;
+        #[doc(hidden)] type ReprPacked = typic::internal::#repr_packed;
+        #[doc(hidden)] type HighLevel = typic::internal::#layout<#variants>;
+      }
+
+      #checks
+    })
+    .into()
+}
+
+fn impl_union(definition: syn::ItemUnion, repr: Repr) -> TokenStream {
+    let name = &definition.ident;
+    let attrs = &definition.attrs;
+    let generics = &definition.generics;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    if repr.method != Some(Method::C) {
+        let error = syn::Error::new_spanned(
+            name,
+            "typic requires unions to have a `C` representation",
         );
+        return emit_error(&definition, error);
+    }
+
+    let repr_align = repr
+        .align
+        .map(|n| format_ident!("U{}", n))
+        .unwrap_or(format_ident!("MinAlign"));
+
+    let repr_packed = repr
+        .packed
+        .map(|n| format_ident!("U{}", n))
+        .unwrap_or(format_ident!("MaxAlign"));
+
+    let checks = if repr.checked {
+        layout_checks(name, generics, None)
+    } else {
+        quote!()
+    };
+
+    let fields = fields(&syn::Fields::Named(definition.fields.clone()), |field| {
+        if let Visibility::Public(_) = field.vis {
+            format_ident!("Public")
+        } else {
+            format_ident!("Private")
+        }
+    });
 
     (quote! {
       #definition
@@ -145,28 +461,129 @@ fn impl_struct(definition: syn::ItemStruct) -> TokenStream {
       {
         #[doc(hidden)] type ReprAlign = typic::internal::#repr_align;
         #[doc(hidden)] type ReprPacked = typic::internal::#repr_packed;
-        #[doc(hidden)] type HighLevel = #fields;
+        #[doc(hidden)] type HighLevel = typic::internal::Union<#fields>;
       }
+
+      #checks
     })
     .into()
 }
 
+/// The value of an integer-literal discriminant, as a two's-complement bit
+/// pattern.
+fn discriminant(expr: &syn::Expr) -> Option<u128> {
+    use syn::{Expr, ExprLit, ExprUnary, UnOp};
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Int(n), .. }) => n.base10_parse::<u128>().ok(),
+        Expr::Unary(ExprUnary { op: UnOp::Neg(_), expr, .. }) => {
+            discriminant(expr).map(|n| n.wrapping_neg())
+        }
+        Expr::Paren(paren) => discriminant(&paren.expr),
+        Expr::Group(group) => discriminant(&group.expr),
+        _ => None,
+    }
+}
+
+/// The lowest `bits` bits of `value`.
+fn truncate(value: u128, bits: u32) -> u128 {
+    if bits >= 128 {
+        value
+    } else {
+        value & ((1 << bits) - 1)
+    }
+}
+
+/// The `typenum` unsigned integer type of `value`.
+fn uint(value: u128) -> TokenStream2 {
+    let bits = 128 - value.leading_zeros();
+    (0..bits).rev().fold(quote!(typic::internal::UTerm), |rest, i| {
+        let bit = if value & (1 << i) == 0 {
+            format_ident!("B0")
+        } else {
+            format_ident!("B1")
+        };
+        quote!(typic::internal::UInt<#rest, typic::internal::#bit>)
+    })
+}
+
 #[proc_macro_attribute]
 pub fn repr(args: TokenStream, input: TokenStream) -> TokenStream {
     let args: TokenStream2 = args.into();
     let input: TokenStream2 = input.into();
     let definition: syn::Item = parse_quote!(#[repr(#args)] #input);
 
+    // If the representation hints are invalid, the definition is emitted
+    // without the hints given to this attribute (the first attribute), so that
+    // rustc does not report the same errors again.
     match definition {
-        syn::Item::Struct(definition) => impl_struct(definition),
-        _ => unimplemented!(),
+        syn::Item::Struct(mut definition) => {
+            match Repr::parse(&definition.attrs).and_then(|repr| repr.check_struct().map(|_| repr)) {
+                Ok(repr) => {
+                    strip_options(&mut definition.attrs);
+                    impl_struct(definition, repr)
+                }
+                Err(error) => {
+                    definition.attrs.remove(0);
+                    strip_options(&mut definition.attrs);
+                    emit_error(&definition, error)
+                }
+            }
+        }
+        syn::Item::Enum(mut definition) => {
+            match Repr::parse(&definition.attrs).and_then(|repr| repr.check_enum().map(|_| repr)) {
+                Ok(repr) => {
+                    strip_options(&mut definition.attrs);
+                    impl_enum(definition, repr)
+                }
+                Err(error) => {
+                    definition.attrs.remove(0);
+                    strip_options(&mut definition.attrs);
+                    emit_error(&definition, error)
+                }
+            }
+        }
+        syn::Item::Union(mut definition) => {
+            match Repr::parse(&definition.attrs).and_then(|repr| repr.check_union().map(|_| repr)) {
+                Ok(repr) => {
+                    strip_options(&mut definition.attrs);
+                    impl_union(definition, repr)
+                }
+                Err(error) => {
+                    definition.attrs.remove(0);
+                    strip_options(&mut definition.attrs);
+                    emit_error(&definition, error)
+                }
+            }
+        }
+        definition => {
+            let error = syn::Error::new(
+                input.span(),
+                "`typic::repr` may only be applied to structs, enums and unions",
+            );
+            emit_error(&input, error)
+        }
     }
 }
 
+/// Remove the typic-specific `#[typic(...)]` attributes from `attrs`.
+fn strip_options(attrs: &mut Vec<Attribute>) {
+    attrs.retain(|attr| !attr.path.is_ident("typic"));
+}
+
+/// Emit `definition`, along with the compile error `error`.
+fn emit_error(definition: &dyn ToTokens, error: syn::Error) -> TokenStream {
+    let error = error.to_compile_error();
+    (quote! {
+      #definition
+      #error
+    })
+    .into()
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 enum Method {
     C,
-    Packed,
+    Rust,
     Transparent,
 }
 
@@ -186,79 +603,299 @@ enum Size {
     USize,
 }
 
-#[derive(Default, Debug, Eq, PartialEq, Clone, Copy)]
+impl Size {
+    /// The size named by the representation hint `hint`, if any.
+    fn from_str(hint: &str) -> Option<Self> {
+        Some(match hint {
+            "i8" => Size::I8,
+            "i16" => Size::I16,
+            "i32" => Size::I32,
+            "i64" => Size::I64,
+            "i128" => Size::I128,
+            "isize" => Size::ISize,
+            "u8" => Size::U8,
+            "u16" => Size::U16,
+            "u32" => Size::U32,
+            "u64" => Size::U64,
+            "u128" => Size::U128,
+            "usize" => Size::USize,
+            _ => return None,
+        })
+    }
+
+    /// The name of the integer type of this size.
+    fn ident(self) -> syn::Ident {
+        format_ident!("{}", match self {
+            Size::I8 => "i8",
+            Size::I16 => "i16",
+            Size::I32 => "i32",
+            Size::I64 => "i64",
+            Size::I128 => "i128",
+            Size::ISize => "isize",
+            Size::U8 => "u8",
+            Size::U16 => "u16",
+            Size::U32 => "u32",
+            Size::U64 => "u64",
+            Size::U128 => "u128",
+            Size::USize => "usize",
+        })
+    }
+
+    /// The width, in bits, of this size, if it does not depend on the target.
+    fn bits(self) -> Option<u32> {
+        match self {
+            Size::I8 | Size::U8 => Some(8),
+            Size::I16 | Size::U16 => Some(16),
+            Size::I32 | Size::U32 => Some(32),
+            Size::I64 | Size::U64 => Some(64),
+            Size::I128 | Size::U128 => Some(128),
+            Size::ISize | Size::USize => None,
+        }
+    }
+}
+
+/// The representation hints of a type, as given by its `#[repr(...)]`
+/// attributes.
+#[derive(Default, Debug, Clone)]
 struct Repr {
     method: Option<Method>,
     align: Option<u32>,
     packed: Option<u32>,
     size: Option<Size>,
+    spans: Spans,
+    /// Whether to assert that typic computes the same layout as rustc. This is
+    /// opted out of with `#[typic(no_layout_check)]`.
+    checked: bool,
+}
+
+/// Where each kind of hint was given, for error reporting.
+#[derive(Default, Debug, Clone)]
+struct Spans {
+    method: Option<Span>,
+    align: Option<Span>,
+    packed: Option<Span>,
+    size: Option<Span>,
 }
 
-impl<'ast> Visit<'ast> for Repr {
-    fn visit_attribute(&mut self, attr: &'ast Attribute) {
-        if_chain! {
-            if let Ok(Meta::List(meta_list)) = attr.parse_meta();
-            if let Some(ident) = meta_list.path.get_ident();
-            if ident.to_string() == "repr";
-            then {
-                for meta in meta_list.nested {
-                    match meta {
-                        NestedMeta::Meta(Meta::Path(path)) => {
-                            let ident = (if let Some(ident) = path.get_ident() {
-                                ident.to_string()
-                            } else {
-                                continue;
-                            });
-
-                            match &ident[..] {
-                                "C" =>
-                                    self.method = Some(Method::C),
-                                "transparent" =>
-                                    self.method = Some(Method::Transparent),
-
-                                "packed" =>
-                                    self.packed = self.packed.min(Some(1)),
-
-                                "i8"    => self.size = Some(Size::I8),
-                                "i16"   => self.size = Some(Size::I16),
-                                "i32"   => self.size = Some(Size::I32),
-                                "i64"   => self.size = Some(Size::I64),
-                                "i132"  => self.size = Some(Size::I128),
-                                "isize" => self.size = Some(Size::ISize),
-                                "u8"    => self.size = Some(Size::U8),
-                                "u16"   => self.size = Some(Size::U16),
-                                "u32"   => self.size = Some(Size::U32),
-                                "u64"   => self.size = Some(Size::U64),
-                                "u132"  => self.size = Some(Size::U128),
-                                "usize" => self.size = Some(Size::USize),
-                                _ => {},
-                            }
-                        },
-                        NestedMeta::Meta(Meta::List(meta_list)) => {
-                            if_chain! {
-                                if let Some(ident) = meta_list.path.get_ident();
-                                if meta_list.nested.len() == 1;
-                                if let Some(n) =  meta_list.nested.first();
-                                if let NestedMeta::Lit(Lit::Int(n)) = n;
-                                let ident = ident.to_string();
-                                if let Ok(n) = n.base10_parse::<u32>();
-                                then {
-                                    match &ident[..] {
-                                        "align" => {
-                                            self.align = self.align.max(Some(n));
-                                        },
-                                        "packed" => {
-                                             self.packed = self.packed.min(Some(n));
-                                        }
-                                        _ => {}
-                                    }
-                                }
-                            }
-                        }
-                        _ => {},
+/// The greatest alignment accepted by rustc.
+const MAX_ALIGN: u32 = 1 << 29;
+
+impl Repr {
+    /// Parse the `#[repr(...)]` attributes among `attrs`, per rustc's rules.
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut repr = Repr {
+            checked: true,
+            ..Repr::default()
+        };
+        for attr in attrs {
+            if attr.path.is_ident("typic") {
+                repr.option(attr)?;
+                continue;
+            }
+            if !attr.path.is_ident("repr") {
+                continue;
+            }
+            match attr.parse_meta()? {
+                Meta::List(list) => {
+                    for hint in &list.nested {
+                        repr.hint(hint)?;
                     }
                 }
+                meta => return Err(syn::Error::new_spanned(
+                    meta,
+                    "malformed `repr` attribute; expected `#[repr(...)]`",
+                )),
+            }
+        }
+        repr.check()?;
+        Ok(repr)
+    }
+
+    /// Record the typic-specific option `#[typic(...)]`, `attr`.
+    fn option(&mut self, attr: &Attribute) -> syn::Result<()> {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            meta => return Err(syn::Error::new_spanned(
+                meta,
+                "malformed `typic` attribute; expected `#[typic(...)]`",
+            )),
+        };
+        for option in &list.nested {
+            match option {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("no_layout_check") => {
+                    self.checked = false;
+                }
+                _ => return Err(syn::Error::new_spanned(option, "unrecognized typic option")),
+            }
+        }
+        Ok(())
+    }
+
+    /// Record the representation hint `hint`.
+    fn hint(&mut self, hint: &NestedMeta) -> syn::Result<()> {
+        let error = |message: &str| Err(syn::Error::new_spanned(hint, message));
+        let span = hint.span();
+
+        let (ident, meta) = match hint {
+            NestedMeta::Meta(meta) => match meta.path().get_ident() {
+                Some(ident) => (ident.to_string(), meta),
+                None => return error("unrecognized representation hint"),
+            },
+            NestedMeta::Lit(_) => return error("unrecognized representation hint"),
+        };
+
+        match (&ident[..], meta) {
+            ("C", Meta::Path(_)) => self.method(Method::C, span),
+            ("transparent", Meta::Path(_)) => self.method(Method::Transparent, span),
+            ("Rust", Meta::Path(_)) => self.method(Method::Rust, span),
+            ("simd", Meta::Path(_)) => error("typic does not support `repr(simd)`"),
+
+            ("packed", Meta::Path(_)) => self.packed(1, span),
+            ("packed", Meta::List(list)) => self.packed(modifier(list, "packed")?, span),
+            ("align", Meta::List(list)) => self.align(modifier(list, "align")?, span),
+            ("align", _) => error("invalid `repr(align)` attribute: `align` needs an argument"),
+            ("packed", _) => error("invalid `repr(packed)` attribute: expected `packed` or `packed(N)`"),
+
+            (_, Meta::Path(_)) => match Size::from_str(&ident) {
+                Some(size) => self.size(size, span),
+                None => error("unrecognized representation hint"),
+            },
+            _ => error("unrecognized representation hint"),
+        }
+    }
+
+    fn method(&mut self, method: Method, span: Span) -> syn::Result<()> {
+        match self.method {
+            Some(existing) if existing != method => Err(syn::Error::new(
+                span,
+                if existing == Method::Transparent || method == Method::Transparent {
+                    "transparent type cannot have other repr hints"
+                } else {
+                    "conflicting representation hints"
+                },
+            )),
+            _ => {
+                self.method = Some(method);
+                self.spans.method = Some(span);
+                Ok(())
+            }
+        }
+    }
+
+    fn size(&mut self, size: Size, span: Span) -> syn::Result<()> {
+        match self.size {
+            Some(existing) if existing != size => Err(syn::Error::new(
+                span,
+                "conflicting representation hints",
+            )),
+            _ => {
+                self.size = Some(size);
+                self.spans.size = Some(span);
+                Ok(())
             }
         }
     }
+
+    /// Multiple `align` modifiers are allowed; the greatest takes effect.
+    fn align(&mut self, n: u32, span: Span) -> syn::Result<()> {
+        self.align = self.align.max(Some(n));
+        self.spans.align = Some(span);
+        Ok(())
+    }
+
+    fn packed(&mut self, n: u32, span: Span) -> syn::Result<()> {
+        if self.packed.is_some() {
+            return Err(syn::Error::new(
+                span,
+                "conflicting packed representation hints",
+            ));
+        }
+        self.packed = Some(n);
+        self.spans.packed = Some(span);
+        Ok(())
+    }
+
+    /// Check the combination of hints, regardless of the kind of item.
+    fn check(&self) -> syn::Result<()> {
+        if let (Some(_), Some(span)) = (self.packed, self.spans.align) {
+            return Err(syn::Error::new(
+                span,
+                "type has conflicting packed and align representation hints",
+            ));
+        }
+
+        if let (Some(Method::Transparent), Some(span)) = (
+            self.method,
+            self.spans.align.or(self.spans.packed).or(self.spans.size),
+        ) {
+            return Err(syn::Error::new(
+                span,
+                "transparent type cannot have other repr hints",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Check that these hints may be applied to a struct.
+    fn check_struct(&self) -> syn::Result<()> {
+        if let Some(span) = self.spans.size {
+            return Err(syn::Error::new(span, "attribute should be applied to an enum"));
+        }
+        Ok(())
+    }
+
+    /// Check that these hints may be applied to an enum.
+    fn check_enum(&self) -> syn::Result<()> {
+        if let Some(span) = self.spans.packed {
+            return Err(syn::Error::new(
+                span,
+                "attribute should be applied to a struct or union",
+            ));
+        }
+        if let (Some(Method::Transparent), Some(span)) = (self.method, self.spans.method) {
+            return Err(syn::Error::new(
+                span,
+                "typic does not support `repr(transparent)` enums",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check that these hints may be applied to a union.
+    fn check_union(&self) -> syn::Result<()> {
+        if let Some(span) = self.spans.size {
+            return Err(syn::Error::new(span, "attribute should be applied to an enum"));
+        }
+        if let (Some(Method::Transparent), Some(span)) = (self.method, self.spans.method) {
+            return Err(syn::Error::new(
+                span,
+                "typic does not support `repr(transparent)` unions",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// The argument of the `align(N)` or `packed(N)` modifier, `list`.
+fn modifier(list: &syn::MetaList, name: &str) -> syn::Result<u32> {
+    let n = match (list.nested.len(), list.nested.first()) {
+        (1, Some(NestedMeta::Lit(Lit::Int(n)))) => n,
+        _ => return Err(syn::Error::new_spanned(
+            list,
+            format!("incorrect `repr({})` attribute format: expected an integer literal", name),
+        )),
+    };
+
+    let error = |reason: &str| Err(syn::Error::new_spanned(
+        n,
+        format!("invalid `repr({})` attribute: {}", name, reason),
+    ));
+
+    match n.base10_parse::<u64>() {
+        Ok(n) if !n.is_power_of_two() => error("not a power of two"),
+        Ok(n) if n > MAX_ALIGN as u64 => error("larger than 2^29"),
+        Ok(n) => Ok(n as u32),
+        Err(_) => error("not an unsuffixed integer"),
+    }
 }
@@ -0,0 +1,43 @@
+//! Generates the table of array lengths in `src/private/num/length.rs`.
+use std::{env, fmt::Write, fs, path::Path};
+
+/// The longest length that typenum's `U<N>` maps.
+const TYPENUM: u64 = 1024;
+
+/// Every length up to this one is listed.
+const DENSE: u64 = 4096;
+
+/// Every power of two up to this one is listed.
+const SPARSE: u32 = 32;
+
+fn main() {
+    let mut lengths = String::new();
+
+    writeln!(lengths, "typenum! {{").unwrap();
+    for n in 0..=TYPENUM {
+        writeln!(lengths, "    {};", n).unwrap();
+    }
+    writeln!(lengths, "}}").unwrap();
+
+    writeln!(lengths, "length! {{").unwrap();
+    let powers = (DENSE.trailing_zeros() + 1..=SPARSE).map(|exp| 1u64 << exp);
+    for n in (TYPENUM + 1..=DENSE).chain(powers) {
+        // A length must fit in the target's `usize`.
+        if n > u64::from(u32::MAX) {
+            writeln!(lengths, "    #[cfg(target_pointer_width = \"64\")]").unwrap();
+        } else if n > u64::from(u16::MAX) {
+            writeln!(
+                lengths,
+                "    #[cfg(any(target_pointer_width = \"32\", target_pointer_width = \"64\"))]"
+            )
+            .unwrap();
+        }
+        let bit = if n % 2 == 0 { "B0" } else { "B1" };
+        writeln!(lengths, "    {} = {}, {};", n, n / 2, bit).unwrap();
+    }
+    writeln!(lengths, "}}").unwrap();
+
+    let out = Path::new(&env::var_os("OUT_DIR").unwrap()).join("length.rs");
+    fs::write(out, lengths).unwrap();
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -25,9 +25,20 @@
 //! let _ : u32 = Foo(16, 12).transmute_into(); // Compile Error!
 //! ```
 //!
+//! Arrays have layouts if their length is at most 4096, or is a power of two
+//! up to 2^32 (2^31 on 32-bit targets). For other lengths, use a
+//! [`GenericArray`](generic_array::GenericArray):
+//! ```compile_fail
+//! # use typic::transmute::StableTransmuteInto;
+//! let _ : [u8; 5000] = [0u16; 2500].transmute_into(); // Compile Error!
+//! ```
+//!
 //! [soundness]: crate::transmute::unsafe_transmutation#when-is-a-transmutation-sound
 //! [safety]: crate::transmute::safe_transmutation
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[doc(hidden)]
 pub mod docs {
     pub mod prelude {
@@ -94,14 +105,136 @@ pub mod private {
 pub use private::highlevel as internal;
 
 /// Use `#[typic::repr(...)]` instead of `#[repr(...)]` on your type definitions.
+///
+/// The representation hints are checked as rustc would check them, so that
+/// typic's understanding of a type's layout never disagrees with rustc's. For
+/// instance, `packed` and `align` may not be combined:
+/// ```compile_fail
+/// #[typic::repr(C, packed, align(4))] // Compile Error!
+/// struct Conflicted(u8, u32);
+/// ```
+/// ...an alignment must be a power of two:
+/// ```compile_fail
+/// #[typic::repr(C, align(3))] // Compile Error!
+/// struct Misaligned(u8, u32);
+/// ```
+/// ...and a `transparent` type cannot have other hints:
+/// ```compile_fail
+/// #[typic::repr(C, transparent)] // Compile Error!
+/// struct Wrapper(u32);
+/// ```
+///
+/// A struct without a `repr` (or with `repr(Rust)`) has an opaque layout: its
+/// size and alignment are those reported by `core::mem`, and it may only be
+/// transmuted into itself, with `neglect::Stability`. The size of a generic
+/// struct cannot be computed this way, so a generic struct without a `repr`
+/// has no layout at all, and may not be transmuted even into itself:
+/// ```compile_fail
+/// use typic::transmute::{neglect, TransmuteInto};
+///
+/// #[typic::repr(Rust)]
+/// #[derive(Default)]
+/// struct Generic<T>(T);
+///
+/// let _: Generic<u8> = TransmuteInto::<_, neglect::Stability>::transmute_into(Generic::<u8>::default()); // Compile Error!
+/// ```
+///
+/// Typic computes the layouts of types itself. To guard against typic's
+/// layout disagreeing with rustc's, `#[typic::repr(...)]` asserts, at compile
+/// time, that the size, alignment and field offsets it computes for the type
+/// are those reported by `core::mem`. These assertions cannot be expressed
+/// for types with type or const parameters, and are omitted for them. They
+/// also require a typic layout for every field, so a type with a field that
+/// has none (e.g., a `Duration`) is rejected:
+/// ```compile_fail
+/// use core::time::Duration;
+///
+/// #[typic::repr(C)] // Compile Error!
+/// struct Timeout(u32, Duration);
+/// ```
+/// ...unless it opts out of them with `#[typic(no_layout_check)]`:
+/// ```
+/// use core::time::Duration;
+///
+/// #[typic::repr(C)]
+/// #[typic(no_layout_check)]
+/// struct Timeout(u32, Duration);
+/// ```
 #[doc(inline)]
 pub use typic_derive::repr;
 
 #[doc(inline)]
 pub use private::stability;
 
+#[doc(inline)]
+pub use private::layout::byteorder;
+
 pub mod transmute;
 
+#[doc(inline)]
+pub use private::transmute::explain::explain;
+
+#[doc(inline)]
+pub use private::target;
+
+/// Assert, at compile time, that a type is [safely][transmute::TransmuteInto]
+/// transmutable into another on each of several [targets][target].
+///
+/// Layouts that differ between targets (e.g., in the width of pointers, or
+/// the alignment of `u64`) are checked from a single build:
+/// ```
+/// use typic::{self, assert_transmutable_on, stability::StableABI};
+/// use typic::target::{Aarch64, I686, Wasm32, X86_64};
+///
+/// #[typic::repr(C)]
+/// #[derive(StableABI)]
+/// pub struct Packet {
+///     pub seq: u64,
+///     pub len: u32,
+///     pub kind: u32,
+/// }
+///
+/// assert_transmutable_on!(Aarch64, I686, Wasm32, X86_64; Packet => [u32; 4]);
+/// assert_transmutable_on!(Aarch64, I686, Wasm32, X86_64; [u8; 16] => Packet);
+/// ```
+///
+/// A type that is transmutable on the host may not be on other targets;
+/// e.g., a `u64` following a `u32` is preceded by padding, except where `u64`
+/// is only 4-aligned:
+/// ```compile_fail
+/// # use typic::{self, assert_transmutable_on, stability::StableABI};
+/// use typic::target::{I686, X86_64};
+///
+/// #[typic::repr(C)]
+/// #[derive(StableABI)]
+/// pub struct Packet {
+///     pub len: u32,
+///     pub seq: u64,
+/// }
+///
+/// assert_transmutable_on!(I686; Packet => [u32; 3]);
+/// assert_transmutable_on!(X86_64; Packet => [u32; 3]); // Compile Error!
+/// ```
+///
+/// Neglected [options][transmute::neglect] may follow the destination type:
+/// ```
+/// # use typic::{assert_transmutable_on, transmute::neglect};
+/// use typic::target::Wasm32;
+///
+/// assert_transmutable_on!(Wasm32; usize => [u8; 4], neglect::Stability);
+/// ```
+#[macro_export]
+macro_rules! assert_transmutable_on {
+    ($($target: ty),+ ; $T: ty => $U: ty) => {
+        $crate::assert_transmutable_on!($($target),+ ; $T => $U, ());
+    };
+    ($($target: ty),+ ; $T: ty => $U: ty, $O: ty) => {
+        $(
+            const _: fn() = $crate::private::transmute::transmutable_on::<$T, $U, $O, $target>;
+        )+
+    };
+}
+
 mod typic {
     pub use super::*;
 }
@@ -160,6 +293,14 @@ pub mod layout {
     use crate::private::{layout, num};
     use generic_array::ArrayLength;
 
+    pub use crate::private::layout::describe::{
+        describe, Describe, Description, Kind, Opaque, Segment, Segments, Value, Variant,
+        Variants, Visibility,
+    };
+
+    #[doc(inline)]
+    pub use crate::private::layout::ranged::{Integer, Ranged};
+
     /// Type-level information about type representation.
     pub trait Layout: layout::Layout<Public> {
         /// The size of `Self`.
@@ -219,10 +360,15 @@ pub mod layout {
 /// Examples demonstrating typic's ability to express common abstractions.
 pub mod extras {
 
-    /// [Zerocopy](https://docs.rs/zerocopy)-style marker traits.
+    /// [Zerocopy](https://docs.rs/zerocopy)-style marker traits, and typed
+    /// views of byte slices.
     pub mod zerocopy {
         use crate::layout::*;
         use crate::transmute::*;
+        use core::marker::PhantomData;
+        use core::mem::{align_of, size_of};
+        use core::ops::{Deref, DerefMut};
+        use core::slice;
         use typenum::U1;
         use generic_array::{ArrayLength as Length, GenericArray as Array};
 
@@ -256,12 +402,272 @@ pub mod extras {
         where
             T: Layout<Align=U1>,
         {}
+
+        /// A mutable or immutable reference to a byte slice.
+        ///
+        /// # Safety
+        /// `split_at` must split the referent at `mid`, as
+        /// [`slice::split_at`](https://doc.rust-lang.org/core/primitive.slice.html#method.split_at)
+        /// does, and the referent must not move or change length while
+        /// `Self` lives.
+        pub unsafe trait ByteSlice: Deref<Target = [u8]> + Sized {
+            /// Splits the slice at `mid`.
+            fn split_at(self, mid: usize) -> (Self, Self);
+        }
+
+        /// A mutable reference to a byte slice.
+        pub unsafe trait ByteSliceMut: ByteSlice + DerefMut {}
+
+        unsafe impl<'a> ByteSlice for &'a [u8] {
+            #[inline]
+            fn split_at(self, mid: usize) -> (Self, Self) {
+                <[u8]>::split_at(self, mid)
+            }
+        }
+
+        unsafe impl<'a> ByteSlice for &'a mut [u8] {
+            #[inline]
+            fn split_at(self, mid: usize) -> (Self, Self) {
+                <[u8]>::split_at_mut(self, mid)
+            }
+        }
+
+        unsafe impl<'a> ByteSliceMut for &'a mut [u8] {}
+
+        /// A typed view of a length- and alignment-checked byte slice.
+        ///
+        /// `Ref<B, T>` dereferences to `T` if a shared reference to
+        /// `size_of::<T>()` bytes is transmutable into a shared reference to
+        /// a `T` (but for alignment, which is checked at runtime), and
+        /// mutably if the same is true of unique references:
+        /// ```
+        /// use typic::{self, extras::zerocopy::Ref, stability::StableABI};
+        /// use typic::transmute::TransmuteSliceInto;
+        ///
+        /// #[typic::repr(C)]
+        /// #[derive(StableABI)]
+        /// pub struct Header {
+        ///     pub kind: u16,
+        ///     pub len: u16,
+        /// }
+        ///
+        /// let mut buf = [0u16; 3];
+        /// let bytes = TransmuteSliceInto::<&mut [u8]>::transmute_slice_into(&mut buf[..]).unwrap();
+        ///
+        /// let (mut header, body) = Ref::<_, Header>::new_prefix(bytes).unwrap();
+        /// header.len = body.len() as u16;
+        /// assert_eq!(buf[1], 2);
+        /// ```
+        ///
+        /// A shared view may not be written through, so it may not view
+        /// interior mutable types:
+        /// ```compile_fail
+        /// use core::sync::atomic::{AtomicU32, Ordering};
+        /// use typic::extras::zerocopy::Ref;
+        ///
+        /// let bytes = [0u8; 4];
+        /// let atomic = Ref::<&[u8], AtomicU32>::new(&bytes[..]).unwrap();
+        /// atomic.store(7, Ordering::Relaxed); // Compile Error!
+        /// ```
+        pub struct Ref<B, T: ?Sized>(B, PhantomData<T>);
+
+        impl<B: ByteSlice, T> Ref<B, T> {
+            /// Views `bytes` as a `T`, if it is exactly the size of `T`
+            /// and suitably aligned.
+            #[inline]
+            pub fn new(bytes: B) -> Option<Ref<B, T>> {
+                if bytes.len() != size_of::<T>() || !aligned::<T>(&bytes) {
+                    return None;
+                }
+                Some(Ref(bytes, PhantomData))
+            }
+
+            /// Views the first `size_of::<T>()` bytes of `bytes` as a `T`,
+            /// if there are enough of them and they are suitably aligned.
+            /// The remaining bytes are returned alongside.
+            #[inline]
+            pub fn new_prefix(bytes: B) -> Option<(Ref<B, T>, B)> {
+                if bytes.len() < size_of::<T>() || !aligned::<T>(&bytes) {
+                    return None;
+                }
+                let (prefix, rest) = bytes.split_at(size_of::<T>());
+                Some((Ref(prefix, PhantomData), rest))
+            }
+
+            /// Views the last `size_of::<T>()` bytes of `bytes` as a `T`,
+            /// if there are enough of them and they are suitably aligned.
+            /// The preceding bytes are returned alongside.
+            #[inline]
+            pub fn new_suffix(bytes: B) -> Option<(B, Ref<B, T>)> {
+                let mid = bytes.len().checked_sub(size_of::<T>())?;
+                let (rest, suffix) = bytes.split_at(mid);
+                if !aligned::<T>(&suffix) {
+                    return None;
+                }
+                Some((rest, Ref(suffix, PhantomData)))
+            }
+        }
+
+        impl<B: ByteSlice, T: Unaligned> Ref<B, T> {
+            /// Views `bytes` as a `T`, if it is exactly the size of `T`.
+            /// `T` has no alignment requirement, so only the length of
+            /// `bytes` is checked.
+            #[inline]
+            pub fn new_unaligned(bytes: B) -> Option<Ref<B, T>> {
+                Ref::new(bytes)
+            }
+
+            /// Views the first `size_of::<T>()` bytes of `bytes` as a `T`,
+            /// if there are enough of them. The remaining bytes are returned
+            /// alongside.
+            #[inline]
+            pub fn new_unaligned_prefix(bytes: B) -> Option<(Ref<B, T>, B)> {
+                Ref::new_prefix(bytes)
+            }
+
+            /// Views the last `size_of::<T>()` bytes of `bytes` as a `T`,
+            /// if there are enough of them. The preceding bytes are returned
+            /// alongside.
+            #[inline]
+            pub fn new_unaligned_suffix(bytes: B) -> Option<(B, Ref<B, T>)> {
+                Ref::new_suffix(bytes)
+            }
+        }
+
+        impl<B: ByteSlice, T> Ref<B, [T]> {
+            /// Views `bytes` as a `[T]`, if its length is a multiple of the
+            /// size of `T` and it is suitably aligned.
+            ///
+            /// # Panics
+            /// If `T` is zero-sized.
+            #[inline]
+            pub fn new_slice(bytes: B) -> Option<Ref<B, [T]>> {
+                assert_ne!(size_of::<T>(), 0, "`T` must not be zero-sized");
+                if bytes.len() % size_of::<T>() != 0 || !aligned::<T>(&bytes) {
+                    return None;
+                }
+                Some(Ref(bytes, PhantomData))
+            }
+        }
+
+        impl<B: ByteSlice, T: ?Sized> Ref<B, T> {
+            /// The viewed bytes.
+            #[inline]
+            pub fn bytes(&self) -> &[u8] {
+                &self.0
+            }
+
+            /// The viewed bytes, consuming the view.
+            #[inline]
+            pub fn into_bytes(self) -> B {
+                self.0
+            }
+        }
+
+        impl<'a, T> Ref<&'a [u8], T>
+        where
+            T: Layout + 'a,
+            &'a Array<u8, SizeOf<T>>: UnsafeTransmuteInto<&'a T, neglect::Alignment>,
+        {
+            /// Converts the view into a reference with the lifetime of the
+            /// viewed bytes.
+            #[inline]
+            pub fn into_ref(self) -> &'a T {
+                // Sound, because `self.0` is a suitably aligned `T`-sized
+                // slice, and a shared reference to such a slice is
+                // transmutable into a shared reference to a `T`.
+                unsafe { &*(self.0.as_ptr() as *const T) }
+            }
+        }
+
+        impl<'a, T> Ref<&'a mut [u8], T>
+        where
+            T: Layout + 'a,
+            &'a mut Array<u8, SizeOf<T>>: UnsafeTransmuteInto<&'a mut T, neglect::Alignment>,
+        {
+            /// Converts the view into a mutable reference with the lifetime
+            /// of the viewed bytes.
+            #[inline]
+            pub fn into_mut(self) -> &'a mut T {
+                // Sound, because `self.0` is a suitably aligned `T`-sized
+                // slice, and a unique reference to such a slice is
+                // transmutable into a unique reference to a `T`.
+                unsafe { &mut *(self.0.as_mut_ptr() as *mut T) }
+            }
+        }
+
+        impl<B: ByteSlice, T> Deref for Ref<B, T>
+        where
+            T: Layout,
+            for<'a> &'a Array<u8, SizeOf<T>>: UnsafeTransmuteInto<&'a T, neglect::Alignment>,
+        {
+            type Target = T;
+
+            #[inline]
+            fn deref(&self) -> &T {
+                // Sound, because `self.0` is a suitably aligned `T`-sized
+                // slice, and a shared reference to such a slice is
+                // transmutable into a shared reference to a `T`.
+                unsafe { &*(self.0.as_ptr() as *const T) }
+            }
+        }
+
+        impl<B: ByteSliceMut, T> DerefMut for Ref<B, T>
+        where
+            T: Layout,
+            for<'a> &'a Array<u8, SizeOf<T>>: UnsafeTransmuteInto<&'a T, neglect::Alignment>,
+            for<'a> &'a mut Array<u8, SizeOf<T>>: UnsafeTransmuteInto<&'a mut T, neglect::Alignment>,
+        {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut T {
+                // Sound, because `self.0` is a suitably aligned `T`-sized
+                // slice, and a unique reference to such a slice is
+                // transmutable into a unique reference to a `T`.
+                unsafe { &mut *(self.0.as_mut_ptr() as *mut T) }
+            }
+        }
+
+        impl<B: ByteSlice, T> Deref for Ref<B, [T]>
+        where
+            for<'a> &'a [u8]: TransmuteSliceInto<&'a [T]>,
+        {
+            type Target = [T];
+
+            #[inline]
+            fn deref(&self) -> &[T] {
+                // Sound, because `self.0` is a suitably aligned slice of a
+                // whole number of `T`s, and a shared reference to it is
+                // transmutable into a shared reference to those `T`s.
+                unsafe {
+                    slice::from_raw_parts(self.0.as_ptr() as *const T, self.0.len() / size_of::<T>())
+                }
+            }
+        }
+
+        impl<B: ByteSliceMut, T> DerefMut for Ref<B, [T]>
+        where
+            for<'a> &'a [u8]: TransmuteSliceInto<&'a [T]>,
+            for<'a> &'a mut [u8]: TransmuteSliceInto<&'a mut [T]>,
+        {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut [T] {
+                // Sound, because `self.0` is a suitably aligned slice of a
+                // whole number of `T`s, and a unique reference to it is
+                // transmutable into a unique reference to those `T`s.
+                let len = self.0.len() / size_of::<T>();
+                unsafe { slice::from_raw_parts_mut(self.0.as_mut_ptr() as *mut T, len) }
+            }
+        }
+
+        fn aligned<T>(bytes: &[u8]) -> bool {
+            bytes.as_ptr() as usize % align_of::<T>() == 0
+        }
     }
 
     /// [Bytemuck](https://docs.rs/bytemuck)-style casting functions.
     pub mod bytemuck {
         use crate::transmute::*;
-        use core::mem::{align_of, size_of, size_of_val};
+        use core::mem::align_of;
 
         /// Try to convert a `&T` into `&U`.
         ///
@@ -285,43 +691,21 @@ pub mod extras {
             }
         }
 
-        use core::slice;
-        use generic_array::{ArrayLength as Length, GenericArray as Array};
-        use crate::layout::*;
-
-        /// Try to convert a `&T` into `&U`.
+        /// Try to convert a `&[T]` into `&[U]`.
         ///
-        /// This produces `None` if the referent isn't appropriately
-        /// aligned, as required by the destination type.
+        /// This produces `None` if the slice isn't appropriately
+        /// aligned, as required by the destination type, or if it
+        /// doesn't span a whole number of `U`s.
         ///
-        /// Like [`bytemuck::try_cast_slice`], except that invariant
-        /// that `T` and `U` have the same size is statically enforced.
-        ///
-        /// If const generics were stable, the trait bound would
-        /// be instead written as just:
-        /// ```ignore
-        /// &'t [T; size_of::<U>()]:
-        ///     TransmuteInto<&'u [U; size_of::<T>()]>
-        /// ```
+        /// Like [`bytemuck::try_cast_slice`], except that the
+        /// compatibility of the element layouts is statically enforced.
         ///
         /// [`bytemuck::try_cast_slice`]: https://docs.rs/bytemuck/1.2.0/bytemuck/fn.try_cast_slice.html
         pub fn try_cast_slice<'t, 'u, T, U>(src: &'t [T]) -> Option<&'u [U]>
         where
-            &'t Array<T, SizeOf<U>>: TransmuteInto<&'u Array<U, SizeOf<T>>>,
-
-            T: Layout,
-            U: Layout,
-            SizeOf<T>: 'u + Length<U>,
-            SizeOf<U>: 't + Length<T>,
+            &'t [T]: TransmuteSliceInto<&'u [U]>,
         {
-            if align_of::<U>() > align_of::<T>() && (src.as_ptr() as usize) % align_of::<U>() != 0 {
-                None
-            } else {
-                let len = size_of_val(src).checked_div(size_of::<U>()).unwrap_or(0);
-                Some(unsafe {
-                  slice::from_raw_parts(src.as_ptr() as *const U, len)
-                })
-            }
+            TransmuteSliceInto::<&'u [U]>::transmute_slice_into(src).ok()
         }
     }
 }
\ No newline at end of file
@@ -5,18 +5,20 @@ pub mod ops;
 pub mod product;
 pub mod slot;
 
-pub use ops::{Add, Sum};
+pub use ops::{Add, Merge, Merged, Sum};
+pub use coproduct::{Cons as CCons, Nil as CNil, Variant};
 pub use product::{Cons as PCons, Nil as PNil};
 
-use crate::private::num::{Sub1, U1};
-use crate::private::target::PointerWidth;
+use crate::private::target::{Host, PointerWidth};
+use slot::bytes::kind::{self, Freeze};
 
-#[cfg(target_endian = "little")]
-pub type NonZeroSeq<Vis, S, Rest> =
-    PCons<slot::NonZeroSlot<Vis, U1>, PCons<slot::InitializedSlot<Vis, Sub1<S>>, Rest>>;
-
-#[cfg(target_endian = "big")]
-pub type NonZeroSeq<Vis, S, Rest> =
-    PCons<slot::InitializedSlot<Vis, Sub1<S>, PCons<slot::NonZeroSlot<Vis, U1>>, Rest>>;
-
-pub type ReferenceBytes<Vis, Rest> = NonZeroSeq<Vis, PointerWidth, Rest>;
+/// The bytes of a reference of kind `K` on `Target`: a non-zero address,
+/// which is interior mutable if the reference is.
+pub type ReferenceBytes<Vis, K, Rest, Target = Host> = PCons<
+    slot::Bytes<
+        Vis,
+        kind::Thawed<<K as Freeze>::Interior, kind::NonZero<PointerWidth<Target>>>,
+        PointerWidth<Target>,
+    >,
+    Rest,
+>;
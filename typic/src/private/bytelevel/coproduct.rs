@@ -1,4 +1,11 @@
+//! The byte-level representation of an `enum`.
+//!
+//! A coproduct occupies a single position in a [product][super::product]
+//! layout. Each of its variants is a complete byte-level layout of the
+//! coproduct (i.e., every variant has the same size), tagged with the bit
+//! pattern of its discriminant.
 use core::marker::PhantomData;
+use crate::private::num::{Bit, IsEqual, UInt, UTerm, B0, B1};
 
 pub trait Coproduct {}
 
@@ -9,3 +16,68 @@ impl Coproduct for Nil {}
 pub struct Cons<H, T>(PhantomData<(H, T)>);
 
 impl<H, T> Coproduct for Cons<H, T> {}
+
+/// A variant with the discriminant `V` and the byte-level layout `Layout`.
+pub struct Variant<V, Layout>(PhantomData<(V, Layout)>);
+
+/// The discriminant with the bit pattern `V` of an enum whose `repr` is a
+/// signed integer.
+pub struct Signed<V>(PhantomData<V>);
+
+/// The bit pattern of the discriminant `Self`.
+pub trait Bits {
+    type Output;
+}
+
+impl Bits for UTerm {
+    type Output = Self;
+}
+
+impl<U, B> Bits for UInt<U, B> {
+    type Output = Self;
+}
+
+impl<V> Bits for Signed<V> {
+    type Output = V;
+}
+
+/// Find the layout of the variant of `Self` whose discriminant has the bit
+/// pattern of `V`.
+///
+/// If no such variant exists, the `Output` is [`Nil`], which no layout is
+/// transmutable into.
+pub trait Find<V> {
+    type Output;
+}
+
+pub type Found<C, V> = <C as Find<V>>::Output;
+
+impl<V> Find<V> for Nil {
+    type Output = Nil;
+}
+
+impl<V, W, Layout, Rest> Find<V> for Cons<Variant<W, Layout>, Rest>
+where
+    V: Bits,
+    W: Bits,
+    <W as Bits>::Output: IsEqual<<V as Bits>::Output>,
+    Rest: Find<V>,
+    <<W as Bits>::Output as IsEqual<<V as Bits>::Output>>::Output: Select<Layout, Found<Rest, V>>,
+{
+    type Output =
+        <<<W as Bits>::Output as IsEqual<<V as Bits>::Output>>::Output
+            as Select<Layout, Found<Rest, V>>>::Output;
+}
+
+/// `if Self { Then } else { Else }`
+pub trait Select<Then, Else>: Bit {
+    type Output;
+}
+
+impl<Then, Else> Select<Then, Else> for B1 {
+    type Output = Then;
+}
+
+impl<Then, Else> Select<Then, Else> for B0 {
+    type Output = Else;
+}
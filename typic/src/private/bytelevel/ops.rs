@@ -1,8 +1,10 @@
 //! Type-level operations on byte-level stuff.
 
-use crate::private::bytelevel::slot::*;
-use crate::private::bytelevel::{product::Product, PCons, PNil};
+use crate::private::bytelevel::slot::{bytes::kind, *};
+use crate::private::bytelevel::{product::Product, CCons, CNil, PCons, PNil, ReferenceBytes, Variant};
 use crate::private::num;
+use crate::private::target;
+use crate::private::transmute::from_layout::{Consume, Flatten};
 
 pub trait Add<RHS> {
     type Output;
@@ -44,3 +46,192 @@ where
 {
     type Output = PCons<LH, <LT as Add<PCons<RH, RT>>>::Output>;
 }
+
+/// Lower the leading slot of a product to `Bytes`, by flattening arrays,
+/// reducing references to their bytes, and erasing coproducts.
+pub trait Lower {
+    type Output;
+}
+
+pub type Lowered<P> = <P as Lower>::Output;
+
+impl Lower for PNil {
+    type Output = PNil;
+}
+
+impl<Vis, K, S, Rest> Lower for PCons<Bytes<Vis, K, S>, Rest> {
+    type Output = Self;
+}
+
+impl<Vis, T, N, Target, Rest> Lower for PCons<Array<Vis, T, N, Target>, Rest>
+where
+    Self: Flatten,
+    <Self as Flatten>::Output: Lower,
+{
+    type Output = Lowered<<Self as Flatten>::Output>;
+}
+
+impl<'a, Vis, K, T, Target, Rest> Lower for PCons<Reference<'a, Vis, K, T, Target>, Rest>
+where
+    K: kind::Freeze,
+    <K as kind::Freeze>::Interior: kind::Thaw<kind::NonZero<target::PointerWidth<Target>>>,
+    Target: target::Target,
+{
+    type Output = ReferenceBytes<Vis, K, Rest, Target>;
+}
+
+/// Make every byte of a layout interior mutable, as the bytes of an
+/// `UnsafeCell` are.
+pub trait IntoInterior {
+    type Output;
+}
+
+pub type InteriorOf<L> = <L as IntoInterior>::Output;
+
+impl IntoInterior for PNil {
+    type Output = PNil;
+}
+
+impl<H, R> IntoInterior for PCons<H, R>
+where
+    H: IntoInterior,
+    R: IntoInterior,
+{
+    type Output = PCons<InteriorOf<H>, InteriorOf<R>>;
+}
+
+impl<Vis, K, S> IntoInterior for Bytes<Vis, K, S>
+where
+    K: kind::Freeze,
+{
+    type Output = Bytes<Vis, kind::Interior<kind::Frozen<K>>, S>;
+}
+
+/// The elements of the array become cells.
+impl<Vis, T, N, Target> IntoInterior for Array<Vis, T, N, Target> {
+    type Output = Array<Vis, core::cell::UnsafeCell<T>, N, Target>;
+}
+
+impl<'a, Vis, K, T, Target> IntoInterior for Reference<'a, Vis, K, T, Target>
+where
+    K: kind::Freeze,
+{
+    type Output = Reference<'a, Vis, kind::Interior<kind::Frozen<K>>, T, Target>;
+}
+
+/// A type without a defined layout becomes a cell of that type, which may
+/// likewise only become itself.
+impl<Vis, T> IntoInterior for Opaque<Vis, T> {
+    type Output = Opaque<Vis, core::cell::UnsafeCell<T>>;
+}
+
+impl IntoInterior for CNil {
+    type Output = CNil;
+}
+
+impl<V, L, Rest> IntoInterior for CCons<Variant<V, L>, Rest>
+where
+    L: IntoInterior,
+    Rest: IntoInterior,
+{
+    type Output = CCons<Variant<V, InteriorOf<L>>, InteriorOf<Rest>>;
+}
+
+/// A coproduct is erased to the merge of its variants.
+impl<H, T, Rest> Lower for PCons<CCons<H, T>, Rest>
+where
+    CCons<H, T>: Erase,
+    <CCons<H, T> as Erase>::Output: Add<Rest>,
+    Sum<<CCons<H, T> as Erase>::Output, Rest>: Lower,
+{
+    type Output = Lowered<Sum<<CCons<H, T> as Erase>::Output, Rest>>;
+}
+
+/// Merge the variants of a coproduct.
+pub trait Erase {
+    type Output;
+}
+
+impl Erase for CNil {
+    type Output = PNil;
+}
+
+impl<V, L, Rest> Erase for CCons<Variant<V, L>, Rest>
+where
+    Rest: Erase,
+    L: Merge<<Rest as Erase>::Output>,
+{
+    type Output = Merged<L, <Rest as Erase>::Output>;
+}
+
+/// Merge two layouts, byte-by-byte, into a layout whose every byte has the
+/// least restrictive kind and visibility of the corresponding bytes of `Self`
+/// and `RHS`.
+pub trait Merge<RHS> {
+    type Output;
+}
+
+pub type Merged<A, B> = <A as Merge<B>>::Output;
+
+impl<A, B> Merge<B> for A
+where
+    A: Lower,
+    B: Lower,
+    Lowered<A>: MergeLowered<Lowered<B>>,
+{
+    type Output = <Lowered<A> as MergeLowered<Lowered<B>>>::Output;
+}
+
+/// Merge two layouts whose leading slots are `Bytes`.
+pub trait MergeLowered<RHS> {
+    type Output;
+}
+
+impl MergeLowered<PNil> for PNil {
+    type Output = PNil;
+}
+
+impl<Vis, K, S, Rest> MergeLowered<PCons<Bytes<Vis, K, S>, Rest>> for PNil {
+    type Output = PCons<Bytes<Vis, K, S>, Rest>;
+}
+
+impl<Vis, K, S, Rest> MergeLowered<PNil> for PCons<Bytes<Vis, K, S>, Rest> {
+    type Output = Self;
+}
+
+#[rustfmt::skip]
+impl<AVis, AKind, ASize, ARest, BVis, BKind, BSize, BRest>
+MergeLowered<PCons<Bytes<BVis, BKind, BSize>, BRest>>
+for PCons<Bytes<AVis, AKind, ASize>, ARest>
+where
+    AVis: num::Min<BVis>,
+    ASize: num::IsEqual<BSize>,
+    AKind: kind::Join<BKind, num::Eq<ASize, BSize>>,
+    ASize: num::Min<BSize>,
+    BSize: Consume<ASize>,
+    AKind: kind::Split<num::Minimum<ASize, BSize>>,
+    BKind: kind::Split<num::Minimum<ASize, BSize>>,
+
+    Bytes<AVis, kind::Remainder<AKind, num::Minimum<ASize, BSize>>, <BSize as Consume<ASize>>::TSize>:
+        Add<ARest>,
+    Bytes<BVis, kind::Remainder<BKind, num::Minimum<ASize, BSize>>, <BSize as Consume<ASize>>::USize>:
+        Add<BRest>,
+
+    Sum<Bytes<AVis, kind::Remainder<AKind, num::Minimum<ASize, BSize>>, <BSize as Consume<ASize>>::TSize>, ARest>:
+        Merge<Sum<Bytes<BVis, kind::Remainder<BKind, num::Minimum<ASize, BSize>>, <BSize as Consume<ASize>>::USize>, BRest>>,
+
+    Bytes<num::Minimum<AVis, BVis>, kind::Joined<AKind, BKind, num::Eq<ASize, BSize>>, num::Minimum<ASize, BSize>>:
+        Add<Merged<
+            Sum<Bytes<AVis, kind::Remainder<AKind, num::Minimum<ASize, BSize>>, <BSize as Consume<ASize>>::TSize>, ARest>,
+            Sum<Bytes<BVis, kind::Remainder<BKind, num::Minimum<ASize, BSize>>, <BSize as Consume<ASize>>::USize>, BRest>,
+        >>,
+{
+    type Output =
+        Sum<
+            Bytes<num::Minimum<AVis, BVis>, kind::Joined<AKind, BKind, num::Eq<ASize, BSize>>, num::Minimum<ASize, BSize>>,
+            Merged<
+                Sum<Bytes<AVis, kind::Remainder<AKind, num::Minimum<ASize, BSize>>, <BSize as Consume<ASize>>::TSize>, ARest>,
+                Sum<Bytes<BVis, kind::Remainder<BKind, num::Minimum<ASize, BSize>>, <BSize as Consume<ASize>>::USize>, BRest>,
+            >,
+        >;
+}
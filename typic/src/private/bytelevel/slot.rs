@@ -1,10 +1,12 @@
 pub mod array;
 pub mod bytes;
+pub mod opaque;
 pub mod reference;
 
 pub use array::Array;
 pub use bytes::Bytes;
-pub use reference::{Reference, Shared, SharedRef, Unique, UniqueRef};
+pub use opaque::Opaque;
+pub use reference::{Owned, OwnedRef, Reference, Shared, SharedRef, Unique, UniqueRef};
 
 /// The data is from a `pub` field
 pub type Pub = crate::internal::Public;
@@ -14,4 +16,5 @@ pub type Priv = crate::internal::Private;
 
 pub type PaddingSlot<Vis, S> = Bytes<Vis, bytes::kind::Uninitialized, S>;
 pub type InitializedSlot<Vis, S> = Bytes<Vis, bytes::kind::Initialized, S>;
-pub type NonZeroSlot<Vis, S> = Bytes<Vis, bytes::kind::NonZero, S>;
+pub type NonZeroSlot<Vis, S> = Bytes<Vis, bytes::kind::NonZero<S>, S>;
+pub type ValueSlot<Vis, V, S> = Bytes<Vis, bytes::kind::Value<V>, S>;
@@ -1,5 +1,6 @@
 //! [T; N]
+use crate::private::target::Host;
 use core::marker::PhantomData;
 
-/// A unique reference to a type `T` with lifetime `'a`.
-pub struct Array<Visibility, T, N>(PhantomData<(Visibility, T, N)>);
+/// An array of `N` elements of type `T`, on `Target`.
+pub struct Array<Visibility, T, N, Target = Host>(PhantomData<(Visibility, T, N, Target)>);
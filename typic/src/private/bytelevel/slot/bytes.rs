@@ -7,12 +7,240 @@ pub struct Bytes<Vis, Kind, Size>(PhantomData<(Vis, Kind, Size)>);
 /// Markers indicating the kind of bit-level validity restrictions that exist
 /// on a `Bytes`.
 pub mod kind {
-    /// The byte(s) must be initialized to a non-zero value.
-    pub struct NonZero;
+    use core::marker::PhantomData;
+    use crate::private::num::{B0, B1, Max, Maximum, Min, Minimum, Sub, Sub1, UInt, UTerm, U0, U1};
 
     /// The byte(s) must be initialized to any value.
     pub struct Initialized;
 
     /// The byte(s) may be uninitialized or initialized.
     pub struct Uninitialized;
+
+    /// The bytes, taken together as an unsigned integer (in the byte order
+    /// of the target), must be initialized to a value in the inclusive range
+    /// `Start..=End`.
+    ///
+    /// Unlike the other kinds, these bytes cannot be split apart: a prefix of
+    /// a `Range` carries none of its restriction.
+    pub struct Range<Start, End>(PhantomData<(Start, End)>);
+
+    /// The byte(s), taken together, must be initialized to exactly the bit
+    /// pattern `V` (e.g., the discriminant of an enum variant).
+    pub type Value<V> = Range<V, V>;
+
+    /// The byte must be initialized to either `0` or `1` (i.e., a `bool`).
+    pub type Bool = Range<U0, U1>;
+
+    /// The `Size` bytes, taken together, must be initialized to a non-zero
+    /// value.
+    pub type NonZero<Size> = Range<U1, <Size as Ones>::Output>;
+
+    /// The largest unsigned integer of `Self` bytes; i.e., `Self` bytes of
+    /// ones.
+    pub trait Ones {
+        type Output;
+    }
+
+    impl Ones for UTerm {
+        type Output = UTerm;
+    }
+
+    impl<A, B> Ones for UInt<A, B>
+    where
+        UInt<A, B>: Sub<B1>,
+        Sub1<UInt<A, B>>: Ones,
+    {
+        type Output =
+            UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<
+                <Sub1<UInt<A, B>> as Ones>::Output,
+            B1>, B1>, B1>, B1>, B1>, B1>, B1>, B1>;
+    }
+
+    /// The bytes, taken together, must be initialized to a Unicode scalar
+    /// value (i.e., a `char`).
+    ///
+    /// Like a `Range`, these bytes cannot be split apart.
+    pub struct Char;
+
+    /// Bytes of kind `K` that may be mutated through a shared reference
+    /// (i.e., the bytes of an `UnsafeCell`).
+    ///
+    /// `K` is never itself `Interior`.
+    pub struct Interior<K>(PhantomData<K>);
+
+    /// Separates the interior mutability of a kind of bytes (or of a kind of
+    /// reference) from its other restrictions.
+    pub trait Freeze {
+        /// `Self`, without interior mutability.
+        type Frozen;
+
+        /// `B1` if `Self` is interior mutable, and `B0` otherwise.
+        type Interior;
+    }
+
+    pub type Frozen<K> = <K as Freeze>::Frozen;
+
+    impl<K> Freeze for Interior<K> {
+        type Frozen = K;
+        type Interior = B1;
+    }
+
+    macro_rules! freeze {
+        ($($({$($G: ident),*})? $K: ty;)*) => {
+            $(
+                impl$(<$($G),*>)? Freeze for $K {
+                    type Frozen = Self;
+                    type Interior = B0;
+                }
+            )*
+        };
+    }
+
+    freeze! {
+        Initialized;
+        Uninitialized;
+        {Start, End} Range<Start, End>;
+        Char;
+    }
+
+    /// `K`, made interior mutable if `Self` is `B1`.
+    pub trait Thaw<K> {
+        type Output;
+    }
+
+    pub type Thawed<Interior, K> = <Interior as Thaw<K>>::Output;
+
+    impl<K> Thaw<K> for B0 {
+        type Output = K;
+    }
+
+    impl<K> Thaw<K> for B1 {
+        type Output = Interior<K>;
+    }
+
+    /// The kind of the bytes that remain after `Consumed` bytes have been
+    /// split off the front of a sequence of bytes of kind `Self`.
+    pub trait Split<Consumed> {
+        type Output;
+    }
+
+    pub type Remainder<K, Consumed> = <K as Split<Consumed>>::Output;
+
+    /// Nothing was split off; the kind is unchanged.
+    impl<K> Split<UTerm> for K {
+        type Output = K;
+    }
+
+    impl<A, B> Split<UInt<A, B>> for Initialized {
+        type Output = Initialized;
+    }
+
+    impl<A, B> Split<UInt<A, B>> for Uninitialized {
+        type Output = Uninitialized;
+    }
+
+    /// The remainder of a range is initialized, but otherwise unrestricted.
+    impl<Start, End, A, B> Split<UInt<A, B>> for Range<Start, End> {
+        type Output = Initialized;
+    }
+
+    impl<A, B> Split<UInt<A, B>> for Char {
+        type Output = Initialized;
+    }
+
+    /// The remainder of interior mutable bytes is interior mutable.
+    impl<K, A, B> Split<UInt<A, B>> for Interior<K>
+    where
+        K: Split<UInt<A, B>>,
+    {
+        type Output = Interior<Remainder<K, UInt<A, B>>>;
+    }
+
+    /// The least restrictive kind that is satisfied by both bytes of kind
+    /// `Self` and bytes of kind `K`. `SameSize` is `B1` if both sequences of
+    /// bytes are of the same size, and `B0` otherwise; only then can the
+    /// restrictions of ranges and `char`s be combined.
+    pub trait Join<K, SameSize> {
+        type Output;
+    }
+
+    pub type Joined<A, B, SameSize> = <A as Join<B, SameSize>>::Output;
+
+    macro_rules! join {
+      ($($({$($G: ident),*})? $A: ty, $B: ty => $Output: ty;)*) => {
+        $(
+          impl<$($($G,)*)? SameSize> Join<$B, SameSize> for $A {
+            type Output = $Output;
+          }
+        )*
+      };
+    }
+
+    join! [
+                        Uninitialized,    Uninitialized => Uninitialized;
+                        Uninitialized,      Initialized => Uninitialized;
+        {S, E}          Uninitialized,      Range<S, E> => Uninitialized;
+                        Uninitialized,             Char => Uninitialized;
+                          Initialized,    Uninitialized => Uninitialized;
+                          Initialized,      Initialized => Initialized;
+        {S, E}            Initialized,      Range<S, E> => Initialized;
+                          Initialized,             Char => Initialized;
+        {S, E}            Range<S, E>,    Uninitialized => Uninitialized;
+        {S, E}            Range<S, E>,      Initialized => Initialized;
+        {S, E}            Range<S, E>,             Char => Initialized;
+                                 Char,    Uninitialized => Uninitialized;
+                                 Char,      Initialized => Initialized;
+        {S, E}                   Char,      Range<S, E> => Initialized;
+    ];
+
+    /// Ranges over the same bytes join to the smallest range covering both.
+    impl<S, E, T, F> Join<Range<T, F>, B1> for Range<S, E>
+    where
+        S: Min<T>,
+        E: Max<F>,
+    {
+        type Output = Range<Minimum<S, T>, Maximum<E, F>>;
+    }
+
+    impl<S, E, T, F> Join<Range<T, F>, B0> for Range<S, E> {
+        type Output = Initialized;
+    }
+
+    impl Join<Char, B1> for Char {
+        type Output = Char;
+    }
+
+    impl Join<Char, B0> for Char {
+        type Output = Initialized;
+    }
+
+    /// Bytes that are interior mutable in either of two layouts are interior
+    /// mutable in their join.
+    impl<K, J, SameSize> Join<J, SameSize> for Interior<K>
+    where
+        J: Freeze,
+        K: Join<Frozen<J>, SameSize>,
+    {
+        type Output = Interior<Joined<K, Frozen<J>, SameSize>>;
+    }
+
+    macro_rules! join_interior {
+        ($($({$($G: ident),*})? $K: ty;)*) => {
+            $(
+                impl<$($($G,)*)? J, SameSize> Join<Interior<J>, SameSize> for $K
+                where
+                    $K: Join<J, SameSize>,
+                {
+                    type Output = Interior<Joined<$K, J, SameSize>>;
+                }
+            )*
+        };
+    }
+
+    join_interior! {
+        Initialized;
+        Uninitialized;
+        {S, E} Range<S, E>;
+        Char;
+    }
 }
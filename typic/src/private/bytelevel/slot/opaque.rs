@@ -0,0 +1,5 @@
+//! The bytes of a type without a defined layout.
+use core::marker::PhantomData;
+
+/// All of the bytes of `T`, a type without a defined layout.
+pub struct Opaque<Visibility, T>(PhantomData<(Visibility, T)>);
@@ -1,14 +1,38 @@
-//! `& T` and `&mut T`
+//! `& T`, `&mut T` and `Box<T>`
 
+use super::bytes::kind::Freeze;
+use crate::private::num::B0;
+use crate::private::target::Host;
 use core::marker::PhantomData;
 
 pub struct Shared;
 pub struct Unique;
+pub struct Owned;
 
-pub struct Reference<'a, Visibility, K, T>(PhantomData<(Visibility, K, &'a T)>);
+macro_rules! freeze {
+    ($($K: ty),*) => {
+        $(
+            impl Freeze for $K {
+                type Frozen = Self;
+                type Interior = B0;
+            }
+        )*
+    };
+}
+
+freeze!(Shared, Unique, Owned);
+
+/// A reference of kind `K` to a type `T`, on `Target`.
+///
+/// The kind of a reference within an `UnsafeCell` is `Interior<K>`; the
+/// reference itself may be replaced through a shared reference.
+pub struct Reference<'a, Visibility, K, T, Target = Host>(PhantomData<(Visibility, K, &'a T, Target)>);
 
 /// A unique reference to a type `T` with lifetime `'a`.
-pub type UniqueRef<'a, Visibility, T> = Reference<'a, Visibility, Unique, T>;
+pub type UniqueRef<'a, Visibility, T, Target = Host> = Reference<'a, Visibility, Unique, T, Target>;
 
 /// A shared reference to a type `T` with lifetime `'a`.
-pub type SharedRef<'a, Visibility, T> = Reference<'a, Visibility, Shared, T>;
+pub type SharedRef<'a, Visibility, T, Target = Host> = Reference<'a, Visibility, Shared, T, Target>;
+
+/// A unique reference to a type `T` that owns (and will free) its referent.
+pub type OwnedRef<Visibility, T, Target = Host> = Reference<'static, Visibility, Owned, T, Target>;
@@ -3,19 +3,28 @@
 pub mod coproduct;
 pub mod product;
 pub mod field;
+pub mod opaque;
+pub mod union;
 
 use crate::private::num::Unsigned;
 
 #[doc(hidden)]
 pub use typenum::consts::*;
 
+#[doc(hidden)]
+pub use typenum::{UInt, UTerm, U};
+
 #[doc(inline)]
 pub use field::{Field, Public, Private};
 
 #[doc(inline)]
-pub use coproduct::{Cons as CCons, Nil as CNil};
+pub use coproduct::{Cons as CCons, Nil as CNil, Discriminant, PointerSized, ReprC, ReprInt, Variant};
+#[doc(inline)]
+pub use opaque::{Extent, Opaque};
 #[doc(inline)]
 pub use product::{Cons as PCons, Nil as PNil};
+#[doc(inline)]
+pub use union::Union;
 
 pub type MinAlign = U1;
 pub type MaxAlign = U536870912;
@@ -9,3 +9,35 @@ impl Coproduct for Nil {}
 pub struct Cons<H, T>(PhantomData<(H, T)>);
 
 impl<H, T> Coproduct for Cons<H, T> {}
+
+/// A variant of an `enum`, whose tag is the [`Discriminant`] `D`, and whose
+/// fields are the product `Fields`.
+pub struct Variant<D, Fields>(PhantomData<(D, Fields)>);
+
+/// The tag of an `enum` variant: a value of the primitive integer type `Repr`
+/// with the bit pattern `V`.
+pub struct Discriminant<Repr, V>(PhantomData<(Repr, V)>);
+
+/// The bit pattern of an `isize` or `usize` discriminant, which depends on
+/// the width of pointers on the target: `V16`, `V32` or `V64` if pointers
+/// are 16, 32 or 64 bits wide.
+pub struct PointerSized<V16, V32, V64>(PhantomData<(V16, V32, V64)>);
+
+/// The layout of an `enum` with a primitive representation (e.g.,
+/// `repr(u8)`): a `repr(C)` union of `repr(C)` structs, each of which begins
+/// with the discriminant. See [RFC 2195].
+///
+/// [RFC 2195]: https://rust-lang.github.io/rfcs/2195-really-tagged-unions.html
+pub struct ReprInt<Variants>(PhantomData<Variants>);
+
+/// The layout of an `enum` with a `C` representation (e.g., `repr(C)` or
+/// `repr(C, u8)`): a `repr(C)` struct of the discriminant, followed by a
+/// `repr(C)` union of `repr(C)` structs of each variant's fields. See
+/// [RFC 2195].
+///
+/// [RFC 2195]: https://rust-lang.github.io/rfcs/2195-really-tagged-unions.html
+pub struct ReprC<Variants>(PhantomData<Variants>);
+
+/// The fields of a variant of a `ReprC` enum: a `repr(C)` struct aligned to
+/// (at least) `Align`, the alignment of the union of all variants' fields.
+pub struct Payload<Align, Fields>(PhantomData<(Align, Fields)>);
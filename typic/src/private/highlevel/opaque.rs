@@ -0,0 +1,29 @@
+use crate::private::num::Unsigned;
+use core::marker::PhantomData;
+
+/// The layout of `T`, a type without a defined representation (e.g., a struct
+/// without a `repr`). Its size and alignment are known, but the arrangement of
+/// its bytes is not.
+pub struct Opaque<T>(PhantomData<T>);
+
+/// The size and alignment of a type without a defined representation, as
+/// given by `core::mem::size_of` and `core::mem::align_of`.
+///
+/// This trait is implemented by `#[typic::repr(...)]` and should **not** be
+/// implemented manually.
+#[diagnostic::on_unimplemented(
+    message = "the size of `{Self}` is unknown to typic",
+    note = "typic cannot determine the size of generic types without a defined layout",
+)]
+pub trait Extent {
+    /// `core::mem::size_of::<Self>()`
+    type Size: Unsigned;
+
+    /// `core::mem::align_of::<Self>()`
+    type Align: Unsigned;
+
+    /// Whether `Self` may be transmuted into itself even if the stability of
+    /// layouts is enforced.
+    #[doc(hidden)]
+    const STABLE: bool = false;
+}
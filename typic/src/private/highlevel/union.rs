@@ -0,0 +1,5 @@
+use core::marker::PhantomData;
+
+/// A `union` whose fields are the product `Fields`. Every field begins at
+/// offset zero.
+pub struct Union<Fields>(PhantomData<Fields>);
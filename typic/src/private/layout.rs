@@ -1,19 +1,26 @@
 use crate::private::highlevel::{HighLevelOf, ReprAlignOf, ReprPackedOf, Type};
-use crate::private::num::Unsigned;
+use crate::private::num::{Unsigned, U0};
 use generic_array::ArrayLength;
 
 mod aligned_to;
+pub mod byteorder;
+pub mod describe;
 
 mod into_bytelevel;
+mod offset;
 mod padding;
+pub mod ranged;
 
 use crate::private::highlevel::Public;
+use crate::private::target::Host;
 pub use aligned_to::AlignedTo;
+pub(crate) use into_bytelevel::primitives::Code;
 use into_bytelevel::IntoByteLevel;
+use offset::FieldOffset;
 use padding::PaddingNeededForField;
 
-/// The actual memory layout characteristics of `Self`.
-pub trait Layout<Visibility = Public> {
+/// The actual memory layout characteristics of `Self` on `Target`.
+pub trait Layout<Visibility = Public, Target = Host> {
     /// The actual alignment of `Self`.
     type Align: Unsigned;
 
@@ -25,7 +32,7 @@ pub trait Layout<Visibility = Public> {
 }
 
 #[rustfmt::skip]
-impl<T, Visibility> Layout<Visibility> for T
+impl<T, Visibility, Target> Layout<Visibility, Target> for T
 where
     T: Type,
 
@@ -34,12 +41,16 @@ where
             ReprAlignOf<T>,
             ReprPackedOf<T>,
             Visibility,
+            U0,
+            Target,
         >,
 
     <HighLevelOf<T> as IntoByteLevel<
             ReprAlignOf<T>,
             ReprPackedOf<T>,
             Visibility,
+            U0,
+            Target,
         >>::Offset: ArrayLength<u8>,
 {
     type Align =
@@ -47,6 +58,8 @@ where
             ReprAlignOf<T>,
             ReprPackedOf<T>,
             Visibility,
+            U0,
+            Target,
         >>::Align;
 
     type Size =
@@ -54,6 +67,8 @@ where
             ReprAlignOf<T>,
             ReprPackedOf<T>,
             Visibility,
+            U0,
+            Target,
         >>::Offset;
 
     type ByteLevel =
@@ -61,8 +76,14 @@ where
             ReprAlignOf<T>,
             ReprPackedOf<T>,
             Visibility,
+            U0,
+            Target,
         >>::Output;
 }
 
+/// The offset of the `Index`th field of the struct `T`.
+pub type FieldOffsetOf<T, Index> =
+    <HighLevelOf<T> as FieldOffset<ReprPackedOf<T>, U0, Index>>::Output;
+
 #[cfg(test)]
 mod test;
@@ -1,13 +1,14 @@
 use super::Layout;
 use crate::private::num::*;
+use crate::private::target::Host;
 use crate::internal::Public;
 
-pub trait AlignedTo<T> {}
+pub trait AlignedTo<T, Target = Host> {}
 
-impl<T, U> AlignedTo<T> for U
+impl<T, U, Target> AlignedTo<T, Target> for U
 where
-    T: Layout<Public>,
-    U: Layout<Public>,
-    <T as Layout<Public>>::Align: PartialDiv<<U as Layout<Public>>::Align>,
+    T: Layout<Public, Target>,
+    U: Layout<Public, Target>,
+    <T as Layout<Public, Target>>::Align: PartialDiv<<U as Layout<Public, Target>>::Align>,
 {
 }
@@ -0,0 +1,216 @@
+//! Integers of a fixed byte order, whose layouts are the same on every
+//! target.
+//!
+//! Each is an array of initialized bytes with an alignment of one, so a
+//! `#[typic::repr(C)]` wire format built of them has no padding, and may be
+//! safely transmuted from bytes on any host:
+//! ```
+//! use typic::{self, byteorder::{U16BE, U32BE}, stability::StableABI};
+//! use typic::transmute::StableTransmuteInto;
+//!
+//! #[typic::repr(C)]
+//! #[derive(StableABI)]
+//! pub struct Header {
+//!     pub kind: U16BE,
+//!     pub len: U32BE,
+//! }
+//!
+//! let mut header: Header = [0u8, 1, 0, 0, 1, 0].transmute_into();
+//! assert_eq!(header.kind.get(), 1);
+//! assert_eq!(header.len.get(), 256);
+//!
+//! header.len.set(2);
+//! let bytes: [u8; 6] = header.transmute_into();
+//! assert_eq!(bytes, [0, 1, 0, 0, 0, 2]);
+//! ```
+//!
+//! The `NonZero*` integers of a fixed byte order may not be zero, so bytes
+//! must be checked before they become them:
+//! ```
+//! use typic::byteorder::NonZeroU16BE;
+//! use typic::transmute::TryTransmuteInto;
+//!
+//! let one = TryTransmuteInto::<NonZeroU16BE>::try_transmute_into([0u8, 1]).unwrap();
+//! assert_eq!(one.get().get(), 1);
+//! assert!(TryTransmuteInto::<NonZeroU16BE>::try_transmute_into([0u8, 0]).is_err());
+//! ```
+use super::into_bytelevel::IntoByteLevel;
+use crate::private::bytelevel::{
+    slot::{InitializedSlot, NonZeroSlot},
+    PCons, PNil,
+};
+use crate::private::highlevel::Type;
+use crate::private::num::*;
+use crate::stability::{TransmutableFrom, TransmutableInto};
+use core::fmt;
+use core::num::*;
+
+macro_rules! layout {
+    ($ty: ident, $size: ty, $slot: ident) => {
+        #[rustfmt::skip]
+        impl Type for $ty {
+            #[doc(hidden)] type ReprAlign  = U1;
+            #[doc(hidden)] type ReprPacked = U1;
+            #[doc(hidden)] type HighLevel = Self;
+        }
+
+        unsafe impl TransmutableFrom for $ty {
+            type Type = Self;
+        }
+
+        unsafe impl TransmutableInto for $ty {
+            type Type = Self;
+        }
+
+        impl<ReprAlign, ReprPacked, Visibility, Offset, Target> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for $ty
+        where
+            Offset: Add<$size>,
+            Sum<Offset, $size>: Unsigned,
+        {
+            type Output = PCons<$slot<Visibility, $size>, PNil>;
+            type Offset = Sum<Offset, $size>;
+            type Align = U1;
+        }
+    };
+}
+
+macro_rules! integer {
+    ($($ty: ident($int: ty, $n: literal, $size: ty, $to: ident, $from: ident, $order: literal);)*) => {
+        $(
+            #[doc = concat!("A `", stringify!($int), "` stored in ", $order, " byte order.")]
+            #[repr(transparent)]
+            #[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+            pub struct $ty([u8; $n]);
+
+            impl $ty {
+                #[doc = concat!("`value`, in ", $order, " byte order.")]
+                pub const fn new(value: $int) -> Self {
+                    $ty(value.$to())
+                }
+
+                /// The value, in the byte order of the target.
+                pub const fn get(self) -> $int {
+                    <$int>::$from(self.0)
+                }
+
+                /// Set the value.
+                pub fn set(&mut self, value: $int) {
+                    *self = Self::new(value);
+                }
+            }
+
+            impl From<$int> for $ty {
+                fn from(value: $int) -> Self {
+                    Self::new(value)
+                }
+            }
+
+            impl From<$ty> for $int {
+                fn from(value: $ty) -> Self {
+                    value.get()
+                }
+            }
+
+            impl fmt::Debug for $ty {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    self.get().fmt(f)
+                }
+            }
+
+            layout!($ty, $size, InitializedSlot);
+        )*
+    };
+}
+
+integer! {
+    U16BE(u16, 2, U2, to_be_bytes, from_be_bytes, "big-endian");
+    U32BE(u32, 4, U4, to_be_bytes, from_be_bytes, "big-endian");
+    U64BE(u64, 8, U8, to_be_bytes, from_be_bytes, "big-endian");
+    U128BE(u128, 16, U16, to_be_bytes, from_be_bytes, "big-endian");
+    I16BE(i16, 2, U2, to_be_bytes, from_be_bytes, "big-endian");
+    I32BE(i32, 4, U4, to_be_bytes, from_be_bytes, "big-endian");
+    I64BE(i64, 8, U8, to_be_bytes, from_be_bytes, "big-endian");
+    I128BE(i128, 16, U16, to_be_bytes, from_be_bytes, "big-endian");
+    U16LE(u16, 2, U2, to_le_bytes, from_le_bytes, "little-endian");
+    U32LE(u32, 4, U4, to_le_bytes, from_le_bytes, "little-endian");
+    U64LE(u64, 8, U8, to_le_bytes, from_le_bytes, "little-endian");
+    U128LE(u128, 16, U16, to_le_bytes, from_le_bytes, "little-endian");
+    I16LE(i16, 2, U2, to_le_bytes, from_le_bytes, "little-endian");
+    I32LE(i32, 4, U4, to_le_bytes, from_le_bytes, "little-endian");
+    I64LE(i64, 8, U8, to_le_bytes, from_le_bytes, "little-endian");
+    I128LE(i128, 16, U16, to_le_bytes, from_le_bytes, "little-endian");
+}
+
+// A value is zero in every byte order if and only if all of its bytes are
+// zero, so these have the byte kind of the target's `NonZero*` integers.
+macro_rules! nonzero {
+    ($($ty: ident($nonzero: ty, $int: ty, $n: literal, $size: ty, $to: ident, $from: ident, $order: literal);)*) => {
+        $(
+            #[doc = concat!("A `", stringify!($nonzero), "` stored in ", $order, " byte order.")]
+            #[repr(transparent)]
+            #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+            pub struct $ty([u8; $n]);
+
+            impl $ty {
+                #[doc = concat!("`value`, in ", $order, " byte order.")]
+                pub const fn new(value: $nonzero) -> Self {
+                    $ty(value.get().$to())
+                }
+
+                /// The value, in the byte order of the target.
+                pub const fn get(self) -> $nonzero {
+                    match <$nonzero>::new(<$int>::$from(self.0)) {
+                        Some(value) => value,
+                        // Sound, because the bytes of `self` are never all
+                        // zero.
+                        None => unsafe { core::hint::unreachable_unchecked() },
+                    }
+                }
+
+                /// Set the value.
+                pub fn set(&mut self, value: $nonzero) {
+                    *self = Self::new(value);
+                }
+            }
+
+            impl From<$nonzero> for $ty {
+                fn from(value: $nonzero) -> Self {
+                    Self::new(value)
+                }
+            }
+
+            impl From<$ty> for $nonzero {
+                fn from(value: $ty) -> Self {
+                    value.get()
+                }
+            }
+
+            impl fmt::Debug for $ty {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    self.get().fmt(f)
+                }
+            }
+
+            layout!($ty, $size, NonZeroSlot);
+        )*
+    };
+}
+
+nonzero! {
+    NonZeroU16BE(NonZeroU16, u16, 2, U2, to_be_bytes, from_be_bytes, "big-endian");
+    NonZeroU32BE(NonZeroU32, u32, 4, U4, to_be_bytes, from_be_bytes, "big-endian");
+    NonZeroU64BE(NonZeroU64, u64, 8, U8, to_be_bytes, from_be_bytes, "big-endian");
+    NonZeroU128BE(NonZeroU128, u128, 16, U16, to_be_bytes, from_be_bytes, "big-endian");
+    NonZeroI16BE(NonZeroI16, i16, 2, U2, to_be_bytes, from_be_bytes, "big-endian");
+    NonZeroI32BE(NonZeroI32, i32, 4, U4, to_be_bytes, from_be_bytes, "big-endian");
+    NonZeroI64BE(NonZeroI64, i64, 8, U8, to_be_bytes, from_be_bytes, "big-endian");
+    NonZeroI128BE(NonZeroI128, i128, 16, U16, to_be_bytes, from_be_bytes, "big-endian");
+    NonZeroU16LE(NonZeroU16, u16, 2, U2, to_le_bytes, from_le_bytes, "little-endian");
+    NonZeroU32LE(NonZeroU32, u32, 4, U4, to_le_bytes, from_le_bytes, "little-endian");
+    NonZeroU64LE(NonZeroU64, u64, 8, U8, to_le_bytes, from_le_bytes, "little-endian");
+    NonZeroU128LE(NonZeroU128, u128, 16, U16, to_le_bytes, from_le_bytes, "little-endian");
+    NonZeroI16LE(NonZeroI16, i16, 2, U2, to_le_bytes, from_le_bytes, "little-endian");
+    NonZeroI32LE(NonZeroI32, i32, 4, U4, to_le_bytes, from_le_bytes, "little-endian");
+    NonZeroI64LE(NonZeroI64, i64, 8, U8, to_le_bytes, from_le_bytes, "little-endian");
+    NonZeroI128LE(NonZeroI128, i128, 16, U16, to_le_bytes, from_le_bytes, "little-endian");
+}
@@ -0,0 +1,644 @@
+//! Lower the type-level layout of a type into a runtime description.
+//!
+//! A layout is described by a linked list of `'static` nodes, one for each
+//! slot of its byte-level representation. The list is built by associated
+//! consts, so descriptions are available (and may be inspected) in `const`
+//! contexts.
+use super::Layout;
+use crate::private::bytelevel::{
+    self as blv,
+    slot::{bytes::kind, Array, Bytes, Owned, Reference, Shared, Unique},
+    CCons, CNil,
+};
+use crate::private::highlevel::{Extent, Private, Public};
+use crate::private::num::{Bit, UInt, UTerm, Unsigned};
+use crate::private::target::PointerWidth;
+use core::any::TypeId;
+use core::fmt;
+use core::marker::PhantomData;
+
+/// A runtime description of the layout of a type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Description {
+    size: usize,
+    align: usize,
+    segments: Segments,
+}
+
+impl Description {
+    /// The size of the type, in bytes.
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The minimum alignment of the type, in bytes.
+    pub const fn align(&self) -> usize {
+        self.align
+    }
+
+    /// The segments of the type's bytes, in order.
+    pub const fn segments(&self) -> Segments {
+        self.segments
+    }
+}
+
+/// A run of bytes of a single kind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Segment {
+    /// The offset of the first byte of the segment.
+    pub offset: usize,
+    /// The number of bytes in the segment.
+    pub size: usize,
+    /// The validity of the segment's bytes.
+    pub kind: Kind,
+    /// Whether the segment's bytes belong to a `pub` field.
+    pub visibility: Visibility,
+    /// Whether the segment's bytes may be mutated through a shared reference
+    /// (i.e., they are the bytes of an `UnsafeCell`).
+    pub interior: bool,
+}
+
+/// The validity of the bytes of a [`Segment`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// The bytes may be uninitialized.
+    Padding,
+    /// The bytes must be initialized, to any value.
+    Initialized,
+    /// The bytes, taken together, must be initialized to exactly this value
+    /// (in the byte order of the target), e.g., an enum discriminant.
+    Value(Value),
+    /// The bytes, taken together as an unsigned integer (in the byte order
+    /// of the target), must be initialized to a value in `start..=end`; e.g.,
+    /// a `bool` or a `NonZeroU32`.
+    Range { start: u128, end: u128 },
+    /// The bytes, taken together, must be initialized to a Unicode scalar
+    /// value; i.e., a `char`.
+    Char,
+    /// A reference to a value described by `referent`: `Box<T>` if `owned`,
+    /// `&mut T` if `unique`, `&T` otherwise.
+    Reference { unique: bool, owned: bool, referent: &'static Description },
+    /// `count` consecutive elements, each described by `element`.
+    Array { element: Description, count: usize },
+    /// One of several alternative arrangements of the bytes (e.g., the
+    /// variants of an enum).
+    Variants(Variants),
+    /// The bytes of a type without a defined layout.
+    Opaque(Opaque),
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Kind::Padding => f.write_str("padding"),
+            Kind::Initialized => f.write_str("initialized bytes"),
+            Kind::Value(value) => write!(f, "the value {}", value),
+            Kind::Range { start, end } => write!(f, "a value in {}..={}", start, end),
+            Kind::Char => f.write_str("a `char`"),
+            Kind::Reference { owned: true, .. } => f.write_str("a `Box`"),
+            Kind::Reference { unique: false, .. } => f.write_str("a shared reference"),
+            Kind::Reference { unique: true, .. } => f.write_str("a unique reference"),
+            Kind::Array { count, .. } => write!(f, "an array of {} elements", count),
+            Kind::Variants(_) => f.write_str("an enum"),
+            Kind::Opaque(opaque) => write!(f, "`{}`", opaque.name()),
+        }
+    }
+}
+
+/// The value of [`Kind::Value`] bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Value {
+    /// An unsigned integer; e.g., the discriminant of a `repr(u8)` enum.
+    Unsigned(u128),
+    /// A signed integer; e.g., the discriminant of a `repr(i16)` enum.
+    Signed(i128),
+}
+
+impl Value {
+    /// The bit pattern of the value, as an integer of `size` bytes.
+    pub const fn bits(self, size: usize) -> u128 {
+        match self {
+            Value::Unsigned(value) => value,
+            Value::Signed(value) if size >= 16 => value as u128,
+            Value::Signed(value) => value as u128 & ((1 << (8 * size)) - 1),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Unsigned(value) => write!(f, "{}", value),
+            Value::Signed(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// The bytes of a type without a defined layout, which may only be
+/// transmuted into that very type.
+#[derive(Clone, Copy)]
+pub struct Opaque {
+    name: fn() -> &'static str,
+    id: fn() -> TypeId,
+    stable: bool,
+}
+
+impl Opaque {
+    /// The name of the type.
+    pub fn name(&self) -> &'static str {
+        (self.name)()
+    }
+
+    /// Whether the type may be transmuted into itself even if the stability
+    /// of layouts is enforced.
+    pub(crate) fn is_stable(&self) -> bool {
+        self.stable
+    }
+}
+
+impl PartialEq for Opaque {
+    fn eq(&self, other: &Self) -> bool {
+        (self.id)() == (other.id)()
+    }
+}
+
+impl Eq for Opaque {}
+
+impl fmt::Debug for Opaque {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Opaque").field(&self.name()).finish()
+    }
+}
+
+/// The `TypeId` of `T`, with its lifetimes erased.
+///
+/// Lifetimes do not affect layouts, so the `TypeId` identifies opaque types
+/// that borrow (e.g., the code of a `fn(&'a u8)`), too.
+fn type_id<T: ?Sized>() -> TypeId {
+    trait Identify {
+        fn type_id(&self) -> TypeId
+        where
+            Self: 'static;
+    }
+
+    impl<T: ?Sized> Identify for PhantomData<T> {
+        fn type_id(&self) -> TypeId
+        where
+            Self: 'static,
+        {
+            TypeId::of::<T>()
+        }
+    }
+
+    let phantom: &dyn Identify = &PhantomData::<T>;
+    // SAFETY: `PhantomData<T>` holds no data, so the trait object outliving
+    // `T`'s lifetimes is never observed; `TypeId::of` erases them regardless.
+    let phantom: &(dyn Identify + 'static) = unsafe { core::mem::transmute(phantom) };
+    phantom.type_id()
+}
+
+/// The visibility of the bytes of a [`Segment`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Visibility {
+    /// The bytes belong to a `pub` field.
+    Public,
+    /// The bytes belong to a field that is not `pub`.
+    Private,
+}
+
+#[doc(hidden)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Node {
+    size: usize,
+    kind: Kind,
+    visibility: Visibility,
+    interior: bool,
+    next: Option<&'static Node>,
+}
+
+/// The segments of a layout, in order. Zero-sized segments are omitted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Segments {
+    node: Option<&'static Node>,
+    offset: usize,
+}
+
+impl Segments {
+    const fn new(node: Option<&'static Node>) -> Self {
+        Segments { node: skip_empty(node), offset: 0 }
+    }
+
+    /// The number of segments.
+    pub const fn len(&self) -> usize {
+        let mut len = 0;
+        let mut node = self.node;
+        while let Some(this) = node {
+            len += 1;
+            node = skip_empty(this.next);
+        }
+        len
+    }
+
+    /// Whether there are no segments.
+    pub const fn is_empty(&self) -> bool {
+        self.node.is_none()
+    }
+
+    /// The `index`th segment, if any.
+    pub const fn get(&self, index: usize) -> Option<Segment> {
+        let mut remaining = index;
+        let mut offset = self.offset;
+        let mut node = self.node;
+        while let Some(this) = node {
+            if remaining == 0 {
+                return Some(Segment {
+                    offset,
+                    size: this.size,
+                    kind: this.kind,
+                    visibility: this.visibility,
+                    interior: this.interior,
+                });
+            }
+            remaining -= 1;
+            offset += this.size;
+            node = skip_empty(this.next);
+        }
+        None
+    }
+
+    /// The total size of the segments, in bytes.
+    const fn size(&self) -> usize {
+        let mut size = 0;
+        let mut node = self.node;
+        while let Some(this) = node {
+            size += this.size;
+            node = this.next;
+        }
+        size
+    }
+}
+
+impl Iterator for Segments {
+    type Item = Segment;
+
+    fn next(&mut self) -> Option<Segment> {
+        let segment = self.get(0)?;
+        self.offset += segment.size;
+        self.node = skip_empty(self.node.and_then(|node| node.next));
+        Some(segment)
+    }
+}
+
+/// The first non-empty node of the list beginning at `node`.
+const fn skip_empty(mut node: Option<&'static Node>) -> Option<&'static Node> {
+    while let Some(this) = node {
+        if this.size != 0 {
+            break;
+        }
+        node = this.next;
+    }
+    node
+}
+
+#[doc(hidden)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct VariantNode {
+    variant: Variant,
+    next: Option<&'static VariantNode>,
+}
+
+/// An arrangement of the bytes of [`Kind::Variants`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Variant {
+    /// The bit pattern of the variant's discriminant.
+    pub discriminant: u128,
+    /// The segments of the variant, beginning at the offset of the
+    /// [`Kind::Variants`] segment.
+    pub segments: Segments,
+}
+
+/// The alternative arrangements of [`Kind::Variants`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Variants {
+    node: Option<&'static VariantNode>,
+}
+
+impl Variants {
+    /// The number of variants.
+    pub const fn len(&self) -> usize {
+        let mut len = 0;
+        let mut node = self.node;
+        while let Some(this) = node {
+            len += 1;
+            node = this.next;
+        }
+        len
+    }
+
+    /// Whether there are no variants.
+    pub const fn is_empty(&self) -> bool {
+        self.node.is_none()
+    }
+
+    /// The `index`th variant, if any.
+    pub const fn get(&self, index: usize) -> Option<Variant> {
+        let mut remaining = index;
+        let mut node = self.node;
+        while let Some(this) = node {
+            if remaining == 0 {
+                return Some(this.variant);
+            }
+            remaining -= 1;
+            node = this.next;
+        }
+        None
+    }
+}
+
+impl Iterator for Variants {
+    type Item = Variant;
+
+    fn next(&mut self) -> Option<Variant> {
+        let node = self.node?;
+        self.node = node.next;
+        Some(node.variant)
+    }
+}
+
+/// Lower the layout of `Self` into a runtime [`Description`].
+pub trait Describe: Layout<Public> {
+    /// The description of `Self`'s layout.
+    const DESCRIPTION: Description;
+}
+
+impl<T> Describe for T
+where
+    T: Layout<Public>,
+    <T as Layout<Public>>::ByteLevel: Lower,
+{
+    const DESCRIPTION: Description = Description {
+        size: <T as Layout<Public>>::Size::USIZE,
+        align: <T as Layout<Public>>::Align::USIZE,
+        segments: Segments::new(<<T as Layout<Public>>::ByteLevel as Lower>::NODE),
+    };
+}
+
+/// Describe the layout of `T`.
+///
+/// ```
+/// use typic::layout::{describe, Kind, Visibility};
+///
+/// #[typic::repr(C)]
+/// struct Padded(pub u8, pub u16);
+///
+/// const PADDED: typic::layout::Description = describe::<Padded>();
+///
+/// let kinds: Vec<_> = PADDED.segments().map(|s| (s.offset, s.size, s.kind)).collect();
+/// assert_eq!(kinds, [
+///     (0, 1, Kind::Initialized),
+///     (1, 1, Kind::Padding),
+///     (2, 2, Kind::Initialized),
+/// ]);
+/// assert!(PADDED.segments().all(|s| s.visibility == Visibility::Public));
+/// ```
+pub const fn describe<T: Describe>() -> Description {
+    T::DESCRIPTION
+}
+
+/// Lower a byte-level product into a list of nodes.
+#[doc(hidden)]
+pub trait Lower {
+    const NODE: Option<&'static Node>;
+}
+
+impl Lower for blv::PNil {
+    const NODE: Option<&'static Node> = None;
+}
+
+impl<H, R> Lower for blv::PCons<H, R>
+where
+    H: Slot,
+    R: Lower,
+{
+    const NODE: Option<&'static Node> = Some(&Node {
+        size: H::SIZE,
+        kind: H::KIND,
+        visibility: H::VISIBILITY,
+        interior: H::INTERIOR,
+        next: R::NODE,
+    });
+}
+
+/// The description of a single byte-level slot.
+#[doc(hidden)]
+pub trait Slot {
+    const SIZE: usize;
+    const KIND: Kind;
+    const VISIBILITY: Visibility;
+    const INTERIOR: bool = false;
+}
+
+#[doc(hidden)]
+pub trait DescribeVisibility {
+    const VISIBILITY: Visibility;
+}
+
+impl DescribeVisibility for Public {
+    const VISIBILITY: Visibility = Visibility::Public;
+}
+
+impl DescribeVisibility for Private {
+    const VISIBILITY: Visibility = Visibility::Private;
+}
+
+#[doc(hidden)]
+pub trait DescribeKind {
+    const KIND: Kind;
+    const INTERIOR: bool = false;
+}
+
+impl<K: DescribeKind> DescribeKind for kind::Interior<K> {
+    const KIND: Kind = K::KIND;
+    const INTERIOR: bool = true;
+}
+
+impl DescribeKind for kind::Uninitialized {
+    const KIND: Kind = Kind::Padding;
+}
+
+impl DescribeKind for kind::Initialized {
+    const KIND: Kind = Kind::Initialized;
+}
+
+impl<S: Unsigned, E: Unsigned> DescribeKind for kind::Range<S, E> {
+    const KIND: Kind = if S::U128 == E::U128 {
+        Kind::Value(Value::Unsigned(S::U128))
+    } else {
+        Kind::Range { start: S::U128, end: E::U128 }
+    };
+}
+
+impl DescribeKind for kind::Char {
+    const KIND: Kind = Kind::Char;
+}
+
+impl<Vis, K, S> Slot for Bytes<Vis, K, S>
+where
+    Vis: DescribeVisibility,
+    K: DescribeKind,
+    S: Unsigned,
+{
+    const SIZE: usize = S::USIZE;
+    const KIND: Kind = K::KIND;
+    const VISIBILITY: Visibility = Vis::VISIBILITY;
+    const INTERIOR: bool = K::INTERIOR;
+}
+
+impl<Vis, T, N> Slot for Array<Vis, T, N>
+where
+    Vis: DescribeVisibility,
+    T: Layout<Vis>,
+    <T as Layout<Vis>>::ByteLevel: Lower,
+    N: Unsigned,
+{
+    const SIZE: usize = <T as Layout<Vis>>::Size::USIZE * N::USIZE;
+    const KIND: Kind = Kind::Array {
+        element: Description {
+            size: <T as Layout<Vis>>::Size::USIZE,
+            align: <T as Layout<Vis>>::Align::USIZE,
+            segments: Segments::new(<<T as Layout<Vis>>::ByteLevel as Lower>::NODE),
+        },
+        count: N::USIZE,
+    };
+    const VISIBILITY: Visibility = Vis::VISIBILITY;
+}
+
+#[doc(hidden)]
+pub trait DescribeMutability {
+    const UNIQUE: bool;
+    const OWNED: bool = false;
+    const INTERIOR: bool = false;
+}
+
+impl<K: DescribeMutability> DescribeMutability for kind::Interior<K> {
+    const UNIQUE: bool = K::UNIQUE;
+    const OWNED: bool = K::OWNED;
+    const INTERIOR: bool = true;
+}
+
+impl DescribeMutability for Shared {
+    const UNIQUE: bool = false;
+}
+
+impl DescribeMutability for Unique {
+    const UNIQUE: bool = true;
+}
+
+impl DescribeMutability for Owned {
+    const UNIQUE: bool = true;
+    const OWNED: bool = true;
+}
+
+impl<'a, Vis, K, T> Slot for Reference<'a, Vis, K, T>
+where
+    Vis: DescribeVisibility,
+    K: DescribeMutability,
+    T: Describe,
+{
+    const SIZE: usize = <PointerWidth as Unsigned>::USIZE;
+    const KIND: Kind = Kind::Reference {
+        unique: K::UNIQUE,
+        owned: K::OWNED,
+        referent: &T::DESCRIPTION,
+    };
+    const VISIBILITY: Visibility = Vis::VISIBILITY;
+    const INTERIOR: bool = K::INTERIOR;
+}
+
+impl<Vis, T> Slot for blv::slot::Opaque<Vis, T>
+where
+    Vis: DescribeVisibility,
+    T: Extent,
+{
+    const SIZE: usize = <T as Extent>::Size::USIZE;
+    const KIND: Kind = Kind::Opaque(Opaque {
+        name: core::any::type_name::<T>,
+        id: type_id::<T>,
+        stable: <T as Extent>::STABLE,
+    });
+    const VISIBILITY: Visibility = Vis::VISIBILITY;
+}
+
+/// Lower the variants of a byte-level coproduct into a list of nodes.
+#[doc(hidden)]
+pub trait LowerVariants {
+    const NODE: Option<&'static VariantNode>;
+}
+
+impl LowerVariants for CNil {
+    const NODE: Option<&'static VariantNode> = None;
+}
+
+impl<V, L, R> LowerVariants for CCons<blv::Variant<V, L>, R>
+where
+    V: LowerDiscriminant<L>,
+    L: Lower,
+    R: LowerVariants,
+{
+    const NODE: Option<&'static VariantNode> = Some(&VariantNode {
+        variant: Variant {
+            discriminant: V::BITS,
+            segments: Segments::new(V::NODE),
+        },
+        next: R::NODE,
+    });
+}
+
+/// Lower the layout `L` of a variant whose discriminant is `Self`.
+#[doc(hidden)]
+pub trait LowerDiscriminant<L> {
+    /// The bit pattern of the discriminant.
+    const BITS: u128;
+    const NODE: Option<&'static Node>;
+}
+
+impl<L: Lower> LowerDiscriminant<L> for UTerm {
+    const BITS: u128 = 0;
+    const NODE: Option<&'static Node> = L::NODE;
+}
+
+impl<U: Unsigned, B: Bit, L: Lower> LowerDiscriminant<L> for UInt<U, B> {
+    const BITS: u128 = <Self as Unsigned>::U128;
+    const NODE: Option<&'static Node> = L::NODE;
+}
+
+/// The leading segment of the variant, its discriminant, is a signed value.
+impl<V: Unsigned, L: Lower> LowerDiscriminant<L> for blv::coproduct::Signed<V> {
+    const BITS: u128 = V::U128;
+    const NODE: Option<&'static Node> = {
+        let head = match skip_empty(L::NODE) {
+            Some(head) => head,
+            None => panic!("a signed discriminant must lead its variant"),
+        };
+        // Sign-extend the `head.size`-byte bit pattern.
+        let shift = 128 - 8 * head.size as u32;
+        Some(&Node {
+            kind: Kind::Value(Value::Signed(((V::U128 << shift) as i128) >> shift)),
+            ..*head
+        })
+    };
+}
+
+/// Every variant of a coproduct has the same size; the coproduct is public,
+/// though the bytes of its variants may not be.
+impl<V, L, R> Slot for CCons<blv::Variant<V, L>, R>
+where
+    Self: LowerVariants,
+    L: Lower,
+{
+    const SIZE: usize = Segments::new(L::NODE).size();
+    const KIND: Kind = Kind::Variants(Variants {
+        node: <Self as LowerVariants>::NODE,
+    });
+    const VISIBILITY: Visibility = Visibility::Public;
+}
@@ -1,12 +1,17 @@
 //! Compute the byte-level layout from a generic representation of a type.
 
 use crate::private::num::{Unsigned, U0};
+use crate::private::target::Host;
 
+pub mod coproduct;
 pub mod field;
+pub mod opaque;
+pub mod option;
 pub mod primitives;
 pub mod product;
+pub mod union;
 
-pub trait IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset = U0> {
+pub trait IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset = U0, Target = Host> {
     /// The byte-level representation of the type.
     type Output;
 
@@ -0,0 +1,354 @@
+//! Compute the byte-level layout of a coproduct (i.e., an `enum`).
+use super::IntoByteLevel;
+use crate::private::bytelevel::{self, slot::{PaddingSlot, ValueSlot}};
+use crate::private::highlevel::{
+    self, coproduct::Payload, Discriminant, Field, MaxAlign, MinAlign, PointerSized, Public,
+    ReprC, ReprInt, Type, Variant,
+};
+use crate::private::layout::Layout;
+use crate::private::num::{self, RoundUpTo, Unsigned, U0, U2, U4, U8};
+use crate::private::target::{self, PointerWidth};
+
+#[rustfmt::skip]
+impl<R, V> Type for Discriminant<R, V>
+where
+    R: Type,
+{
+    #[doc(hidden)] type ReprAlign  = <R as Type>::ReprAlign;
+    #[doc(hidden)] type ReprPacked = <R as Type>::ReprPacked;
+    #[doc(hidden)] type HighLevel = Self;
+}
+
+/// The bit pattern of the discriminant value `Self` on `Target`.
+pub trait DiscriminantValue<Target> {
+    type Output;
+}
+
+pub type ValueOn<V, Target> = <V as DiscriminantValue<Target>>::Output;
+
+impl<Target> DiscriminantValue<Target> for num::UTerm {
+    type Output = Self;
+}
+
+impl<U, B, Target> DiscriminantValue<Target> for num::UInt<U, B> {
+    type Output = Self;
+}
+
+impl<V16, V32, V64, Target> DiscriminantValue<Target> for PointerSized<V16, V32, V64>
+where
+    Target: target::Target,
+    PointerWidth<Target>: Width<V16, V32, V64>,
+{
+    type Output = <PointerWidth<Target> as Width<V16, V32, V64>>::Output;
+}
+
+/// Selects `V16`, `V32` or `V64` for a pointer width of `Self` bytes.
+pub trait Width<V16, V32, V64> {
+    type Output;
+}
+
+impl<V16, V32, V64> Width<V16, V32, V64> for U2 {
+    type Output = V16;
+}
+
+impl<V16, V32, V64> Width<V16, V32, V64> for U4 {
+    type Output = V32;
+}
+
+impl<V16, V32, V64> Width<V16, V32, V64> for U8 {
+    type Output = V64;
+}
+
+/// The tag of a variant whose discriminant, an integer of type `Self`, has
+/// the bit pattern `V`. The discriminants of signed integers are tagged
+/// [`Signed`](bytelevel::coproduct::Signed), so they are described as such.
+pub trait DiscriminantTag<V> {
+    type Output;
+}
+
+macro_rules! discriminant_tag {
+    (unsigned: $($unsigned: ty),*; signed: $($signed: ty),*;) => {
+        $(
+            impl<V> DiscriminantTag<V> for $unsigned {
+                type Output = V;
+            }
+        )*
+        $(
+            impl<V> DiscriminantTag<V> for $signed {
+                type Output = bytelevel::coproduct::Signed<V>;
+            }
+        )*
+    };
+}
+
+discriminant_tag! {
+    unsigned: u8, u16, u32, u64, u128, usize;
+    signed: i8, i16, i32, i64, i128, isize;
+}
+
+/// The discriminant `V` has the size and alignment of its representation,
+/// `R`, but its bytes may only ever have the value `V`.
+#[rustfmt::skip]
+impl<ReprAlign, ReprPacked, Visibility, Offset, Target, R, V>
+IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for Discriminant<R, V>
+where
+    R: Layout<Visibility, Target>,
+    V: DiscriminantValue<Target>,
+    Offset: num::Add<<R as Layout<Visibility, Target>>::Size>,
+    num::Sum<Offset, <R as Layout<Visibility, Target>>::Size>: Unsigned,
+{
+    type Output =
+        bytelevel::PCons<
+            ValueSlot<Visibility, ValueOn<V, Target>, <R as Layout<Visibility, Target>>::Size>,
+            bytelevel::PNil,
+        >;
+
+    type Offset = num::Sum<Offset, <R as Layout<Visibility, Target>>::Size>;
+
+    type Align = <R as Layout<Visibility, Target>>::Align;
+}
+
+#[rustfmt::skip]
+impl<Align, Fields> Type for Payload<Align, Fields>
+where
+    Align: Unsigned,
+{
+    #[doc(hidden)] type ReprAlign  = Align;
+    #[doc(hidden)] type ReprPacked = MaxAlign;
+    #[doc(hidden)] type HighLevel = Fields;
+}
+
+/// A variant is laid out as a `repr(C)` struct whose first field is the
+/// discriminant.
+type Struct<R, V, Fields> = highlevel::PCons<Field<Public, Discriminant<R, V>>, Fields>;
+
+/// The size and alignment of the largest and most-aligned variants of a
+/// coproduct.
+pub trait VariantsExtent<ReprAlign, ReprPacked, Visibility, Target> {
+    /// The size of the largest variant.
+    type Size: Unsigned;
+
+    /// The alignment of the most-aligned variant.
+    type Align: Unsigned;
+}
+
+#[rustfmt::skip]
+impl<ReprAlign, ReprPacked, Visibility, Target>
+VariantsExtent<ReprAlign, ReprPacked, Visibility, Target> for highlevel::CNil
+where
+    ReprAlign: Unsigned,
+{
+    type Size = U0;
+    type Align = ReprAlign;
+}
+
+#[rustfmt::skip]
+impl<ReprAlign, ReprPacked, Visibility, Target, R, V, Fields, Rest>
+VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>
+for highlevel::CCons<Variant<Discriminant<R, V>, Fields>, Rest>
+where
+    Struct<R, V, Fields>: IntoByteLevel<ReprAlign, ReprPacked, Visibility, U0, Target>,
+    Rest: VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>,
+
+    <Struct<R, V, Fields> as IntoByteLevel<ReprAlign, ReprPacked, Visibility, U0, Target>>::Offset:
+        num::Max<<Rest as VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>>::Size>,
+
+    <Struct<R, V, Fields> as IntoByteLevel<ReprAlign, ReprPacked, Visibility, U0, Target>>::Align:
+        num::Max<<Rest as VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>>::Align>,
+
+    num::Maximum<
+        <Struct<R, V, Fields> as IntoByteLevel<ReprAlign, ReprPacked, Visibility, U0, Target>>::Offset,
+        <Rest as VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>>::Size,
+    >: Unsigned,
+
+    num::Maximum<
+        <Struct<R, V, Fields> as IntoByteLevel<ReprAlign, ReprPacked, Visibility, U0, Target>>::Align,
+        <Rest as VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>>::Align,
+    >: Unsigned,
+{
+    type Size =
+        num::Maximum<
+            <Struct<R, V, Fields> as IntoByteLevel<ReprAlign, ReprPacked, Visibility, U0, Target>>::Offset,
+            <Rest as VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>>::Size,
+        >;
+
+    type Align =
+        num::Maximum<
+            <Struct<R, V, Fields> as IntoByteLevel<ReprAlign, ReprPacked, Visibility, U0, Target>>::Align,
+            <Rest as VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>>::Align,
+        >;
+}
+
+/// Compute the byte-level layouts of each variant of a coproduct, each padded
+/// to `Size`.
+pub trait VariantsIntoByteLevel<ReprAlign, ReprPacked, Visibility, Size, Target> {
+    /// The byte-level representation of the variants.
+    type Output;
+}
+
+#[rustfmt::skip]
+impl<ReprAlign, ReprPacked, Visibility, Target, Size>
+VariantsIntoByteLevel<ReprAlign, ReprPacked, Visibility, Size, Target> for highlevel::CNil
+{
+    type Output = bytelevel::CNil;
+}
+
+#[rustfmt::skip]
+impl<ReprAlign, ReprPacked, Visibility, Target, Size, R, V, Fields, Rest>
+VariantsIntoByteLevel<ReprAlign, ReprPacked, Visibility, Size, Target>
+for highlevel::CCons<Variant<Discriminant<R, V>, Fields>, Rest>
+where
+    Struct<R, V, Fields>: IntoByteLevel<ReprAlign, ReprPacked, Visibility, U0, Target>,
+    Rest: VariantsIntoByteLevel<ReprAlign, ReprPacked, Visibility, Size, Target>,
+    V: DiscriminantValue<Target>,
+    R: DiscriminantTag<ValueOn<V, Target>>,
+
+    Size: num::Sub<<Struct<R, V, Fields> as IntoByteLevel<ReprAlign, ReprPacked, Visibility, U0, Target>>::Offset>,
+
+    <Struct<R, V, Fields> as IntoByteLevel<ReprAlign, ReprPacked, Visibility, U0, Target>>::Output:
+        bytelevel::Add<
+            bytelevel::PCons<
+                PaddingSlot<Visibility,
+                    num::Diff<Size,
+                        <Struct<R, V, Fields> as IntoByteLevel<ReprAlign, ReprPacked, Visibility, U0, Target>>::Offset>>,
+                bytelevel::PNil,
+            >
+        >,
+{
+    type Output =
+        bytelevel::CCons<
+            bytelevel::Variant<<R as DiscriminantTag<ValueOn<V, Target>>>::Output,
+                bytelevel::Sum<
+                    <Struct<R, V, Fields> as IntoByteLevel<ReprAlign, ReprPacked, Visibility, U0, Target>>::Output,
+                    bytelevel::PCons<
+                        PaddingSlot<Visibility,
+                            num::Diff<Size,
+                                <Struct<R, V, Fields> as IntoByteLevel<ReprAlign, ReprPacked, Visibility, U0, Target>>::Offset>>,
+                        bytelevel::PNil,
+                    >,
+                >,
+            >,
+            <Rest as VariantsIntoByteLevel<ReprAlign, ReprPacked, Visibility, Size, Target>>::Output,
+        >;
+}
+
+/// A coproduct occupies a single slot of the byte-level layout. Its size is
+/// that of its largest variant, rounded up to its alignment.
+#[rustfmt::skip]
+impl<ReprAlign, ReprPacked, Visibility, Offset, Target, Variants>
+IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for ReprInt<Variants>
+where
+    Variants: VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>,
+
+    <Variants as VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>>::Size:
+        RoundUpTo<<Variants as VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>>::Align>,
+
+    Variants: VariantsIntoByteLevel<ReprAlign, ReprPacked, Visibility,
+        <<Variants as VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>>::Size
+            as RoundUpTo<<Variants as VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>>::Align>>::Output,
+        Target>,
+
+    Offset: num::Add<
+        <<Variants as VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>>::Size
+            as RoundUpTo<<Variants as VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>>::Align>>::Output>,
+
+    num::Sum<Offset,
+        <<Variants as VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>>::Size
+            as RoundUpTo<<Variants as VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>>::Align>>::Output,
+    >: Unsigned,
+{
+    type Output =
+        bytelevel::PCons<
+            <Variants as VariantsIntoByteLevel<ReprAlign, ReprPacked, Visibility,
+                <<Variants as VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>>::Size
+                    as RoundUpTo<<Variants as VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>>::Align>>::Output,
+                Target,
+            >>::Output,
+            bytelevel::PNil,
+        >;
+
+    type Offset =
+        num::Sum<Offset,
+            <<Variants as VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>>::Size
+                as RoundUpTo<<Variants as VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>>::Align>>::Output,
+        >;
+
+    type Align = <Variants as VariantsExtent<ReprAlign, ReprPacked, Visibility, Target>>::Align;
+}
+
+/// The alignment of the union of the fields of each variant.
+pub trait UnionAlign<Visibility, Target> {
+    type Output: Unsigned;
+}
+
+impl<Visibility, Target> UnionAlign<Visibility, Target> for highlevel::CNil {
+    type Output = MinAlign;
+}
+
+#[rustfmt::skip]
+impl<Visibility, Target, D, Fields, Rest> UnionAlign<Visibility, Target>
+for highlevel::CCons<Variant<D, Fields>, Rest>
+where
+    Payload<MinAlign, Fields>: Layout<Visibility, Target>,
+    Rest: UnionAlign<Visibility, Target>,
+    <Payload<MinAlign, Fields> as Layout<Visibility, Target>>::Align:
+        num::Max<<Rest as UnionAlign<Visibility, Target>>::Output>,
+    num::Maximum<
+        <Payload<MinAlign, Fields> as Layout<Visibility, Target>>::Align,
+        <Rest as UnionAlign<Visibility, Target>>::Output,
+    >: Unsigned,
+{
+    type Output =
+        num::Maximum<
+            <Payload<MinAlign, Fields> as Layout<Visibility, Target>>::Align,
+            <Rest as UnionAlign<Visibility, Target>>::Output,
+        >;
+}
+
+/// Wrap the fields of each variant in a [`Payload`] aligned to `Align`.
+pub trait IntoPayloads<Align> {
+    type Output;
+}
+
+impl<Align> IntoPayloads<Align> for highlevel::CNil {
+    type Output = highlevel::CNil;
+}
+
+#[rustfmt::skip]
+impl<Align, D, Fields, Rest> IntoPayloads<Align>
+for highlevel::CCons<Variant<D, Fields>, Rest>
+where
+    Rest: IntoPayloads<Align>,
+{
+    type Output =
+        highlevel::CCons<
+            Variant<D,
+                highlevel::PCons<Field<Public, Payload<Align, Fields>>, highlevel::PNil>>,
+            <Rest as IntoPayloads<Align>>::Output,
+        >;
+}
+
+/// A `repr(C)` enum is laid out like a `ReprInt` enum whose variants are a
+/// discriminant followed by that variant's fields, aligned to the union of
+/// all variants' fields.
+#[rustfmt::skip]
+impl<ReprAlign, ReprPacked, Visibility, Offset, Target, Variants>
+IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for ReprC<Variants>
+where
+    Variants: UnionAlign<Visibility, Target>,
+    Variants: IntoPayloads<<Variants as UnionAlign<Visibility, Target>>::Output>,
+    ReprInt<<Variants as IntoPayloads<<Variants as UnionAlign<Visibility, Target>>::Output>>::Output>:
+        IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target>,
+{
+    type Output =
+        <ReprInt<<Variants as IntoPayloads<<Variants as UnionAlign<Visibility, Target>>::Output>>::Output>
+            as IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target>>::Output;
+
+    type Offset =
+        <ReprInt<<Variants as IntoPayloads<<Variants as UnionAlign<Visibility, Target>>::Output>>::Output>
+            as IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target>>::Offset;
+
+    type Align =
+        <ReprInt<<Variants as IntoPayloads<<Variants as UnionAlign<Visibility, Target>>::Output>>::Output>
+            as IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target>>::Align;
+}
@@ -2,8 +2,9 @@ use crate::private::bytelevel::{slot::PaddingSlot, PCons};
 use crate::private::layout::{Layout, PaddingNeededForField};
 use crate::private::num::{self, Minimum, Min, Unsigned};
 use crate::internal::Field;
+use crate::private::target::Host;
 
-pub trait FieldIntoByteLevel<Packed, Visibility, Offset> {
+pub trait FieldIntoByteLevel<Packed, Visibility, Offset, Target = Host> {
     /// The padded, byte-level representation of `Self`.
     type Output;
 
@@ -14,32 +15,32 @@ pub trait FieldIntoByteLevel<Packed, Visibility, Offset> {
     type Align: Unsigned;
 }
 
-impl<Packed, Visibility, Offset, V, F> FieldIntoByteLevel<Packed, Visibility, Offset> for Field<V, F>
+impl<Packed, Visibility, Offset, Target, V, F> FieldIntoByteLevel<Packed, Visibility, Offset, Target> for Field<V, F>
 where
-    F: Layout<Minimum<V, Visibility>> + PaddingNeededForField<Minimum<V, Visibility>, Offset, Packed>,
+    F: Layout<Minimum<V, Visibility>, Target> + PaddingNeededForField<Minimum<V, Visibility>, Offset, Packed, Target>,
     V: Min<Visibility>,
-    Offset: num::Add<<F as PaddingNeededForField<Minimum<V, Visibility>, Offset, Packed>>::Output>,
+    Offset: num::Add<<F as PaddingNeededForField<Minimum<V, Visibility>, Offset, Packed, Target>>::Output>,
 
-    num::Sum<Offset, <F as PaddingNeededForField<Minimum<V, Visibility>, Offset, Packed>>::Output>:
-      num::Add<<F as Layout<Minimum<V, Visibility>>>::Size>,
+    num::Sum<Offset, <F as PaddingNeededForField<Minimum<V, Visibility>, Offset, Packed, Target>>::Output>:
+      num::Add<<F as Layout<Minimum<V, Visibility>, Target>>::Size>,
 
     num::Sum<
-      num::Sum<Offset, <F as PaddingNeededForField<Minimum<V, Visibility>, Offset, Packed>>::Output>,
-      <F as Layout<Minimum<V, Visibility>>>::Size
+      num::Sum<Offset, <F as PaddingNeededForField<Minimum<V, Visibility>, Offset, Packed, Target>>::Output>,
+      <F as Layout<Minimum<V, Visibility>, Target>>::Size
     >: Unsigned,
 
-    Packed: num::Min<<F as Layout<Minimum<V, Visibility>>>::Align>,
-    num::Minimum<Packed, <F as Layout<Minimum<V, Visibility>>>::Align>: Unsigned,
+    Packed: num::Min<<F as Layout<Minimum<V, Visibility>, Target>>::Align>,
+    num::Minimum<Packed, <F as Layout<Minimum<V, Visibility>, Target>>::Align>: Unsigned,
 {
     type Output = PCons<
-        PaddingSlot<Visibility, <F as PaddingNeededForField<Minimum<V, Visibility>, Offset, Packed>>::Output>,
-        <F as Layout<Minimum<V, Visibility>>>::ByteLevel,
+        PaddingSlot<Visibility, <F as PaddingNeededForField<Minimum<V, Visibility>, Offset, Packed, Target>>::Output>,
+        <F as Layout<Minimum<V, Visibility>, Target>>::ByteLevel,
     >;
 
     type Offset = num::Sum<
-      num::Sum<Offset, <F as PaddingNeededForField<Minimum<V, Visibility>, Offset, Packed>>::Output>,
-      <F as Layout<Minimum<V, Visibility>>>::Size
+      num::Sum<Offset, <F as PaddingNeededForField<Minimum<V, Visibility>, Offset, Packed, Target>>::Output>,
+      <F as Layout<Minimum<V, Visibility>, Target>>::Size
     >;
 
-    type Align = num::Minimum<Packed, <F as Layout<Minimum<V, Visibility>>>::Align>;
+    type Align = num::Minimum<Packed, <F as Layout<Minimum<V, Visibility>, Target>>::Align>;
 }
@@ -0,0 +1,22 @@
+//! Compute the byte-level layout of a type without a defined representation.
+//!
+//! Such a type is a single, indivisible slot whose size and alignment are
+//! those reported by `core::mem`, regardless of its `align` and `packed`
+//! modifiers (which `core::mem` already accounts for).
+use super::IntoByteLevel;
+use crate::private::bytelevel::{slot, PCons, PNil};
+use crate::private::highlevel::{Extent, Opaque};
+use crate::private::num::{self, Unsigned};
+
+#[rustfmt::skip]
+impl<ReprAlign, ReprPacked, Visibility, Offset, Target, T>
+IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for Opaque<T>
+where
+    T: Extent,
+    Offset: num::Add<<T as Extent>::Size>,
+    num::Sum<Offset, <T as Extent>::Size>: Unsigned,
+{
+    type Output = PCons<slot::Opaque<Visibility, T>, PNil>;
+    type Offset = num::Sum<Offset, <T as Extent>::Size>;
+    type Align  = <T as Extent>::Align;
+}
@@ -0,0 +1,112 @@
+//! Compute the byte-level layout of niche-optimized `Option`s.
+//!
+//! `Option<T>` is guaranteed to have the size of `T` (with `None` represented
+//! by zero) when `T` is a `NonZero*` integer, a reference, a `NonNull`, a
+//! `Box`, or a function pointer.
+use super::primitives::Code;
+use super::IntoByteLevel;
+use crate::private::bytelevel::{
+    slot::{InitializedSlot, OwnedRef, SharedRef, UniqueRef, ValueSlot},
+    CCons, CNil, PCons, PNil, Variant,
+};
+use crate::private::highlevel::{MaxAlign, MinAlign, Type};
+use crate::private::num::*;
+use crate::private::target::{self, Host, PointerWidth, U64Align, U128Align};
+use crate::stability::{TransmutableFrom, TransmutableInto};
+use core::num::*;
+use core::ptr::NonNull;
+
+/// A pointer that is either null (`None`, the variant with discriminant `0`)
+/// or the non-null pointer `Some` (the variant with discriminant `1`).
+pub type Nullable<Vis, Some, Target = Host> = CCons<
+    Variant<U0, PCons<ValueSlot<Vis, U0, PointerWidth<Target>>, PNil>>,
+    CCons<Variant<U1, PCons<Some, PNil>>, CNil>,
+>;
+
+macro_rules! option_layout {
+    ($({$($G: tt)*} $ty: ty { size: $size: ty, align: $align: ty, output: $output: ty };)*) => {
+        $(
+            #[rustfmt::skip]
+            impl<$($G)*> Type for Option<$ty> {
+                #[doc(hidden)] type ReprAlign  = MinAlign;
+                #[doc(hidden)] type ReprPacked = MaxAlign;
+                #[doc(hidden)] type HighLevel = Self;
+            }
+
+            unsafe impl<$($G)*> TransmutableFrom for Option<$ty> {
+                type Type = Self;
+            }
+
+            unsafe impl<$($G)*> TransmutableInto for Option<$ty> {
+                type Type = Self;
+            }
+
+            impl<$($G)* ReprAlign, ReprPacked, Visibility, Offset, Target>
+                IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for Option<$ty>
+            where
+                Target: target::Target,
+                Offset: Add<$size>,
+                Sum<Offset, $size>: Unsigned,
+            {
+                type Output = PCons<$output, PNil>;
+                type Offset = Sum<Offset, $size>;
+                type Align  = $align;
+            }
+        )*
+    };
+}
+
+// Every bit pattern of these is valid, since `None` is zero.
+option_layout! {
+    {} NonZeroU8    { size: U1,                    align: U1,                    output: InitializedSlot<Visibility, U1>                  };
+    {} NonZeroU16   { size: U2,                    align: U2,                    output: InitializedSlot<Visibility, U2>                  };
+    {} NonZeroU32   { size: U4,                    align: U4,                    output: InitializedSlot<Visibility, U4>                  };
+    {} NonZeroU64   { size: U8,                    align: U64Align<Target>,      output: InitializedSlot<Visibility, U8>                  };
+    {} NonZeroU128  { size: U16,                   align: U128Align<Target>,     output: InitializedSlot<Visibility, U16>                 };
+    {} NonZeroI8    { size: U1,                    align: U1,                    output: InitializedSlot<Visibility, U1>                  };
+    {} NonZeroI16   { size: U2,                    align: U2,                    output: InitializedSlot<Visibility, U2>                  };
+    {} NonZeroI32   { size: U4,                    align: U4,                    output: InitializedSlot<Visibility, U4>                  };
+    {} NonZeroI64   { size: U8,                    align: U64Align<Target>,      output: InitializedSlot<Visibility, U8>                  };
+    {} NonZeroI128  { size: U16,                   align: U128Align<Target>,     output: InitializedSlot<Visibility, U16>                 };
+    {} NonZeroIsize { size: PointerWidth<Target>,  align: PointerWidth<Target>,  output: InitializedSlot<Visibility, PointerWidth<Target>>};
+    {} NonZeroUsize { size: PointerWidth<Target>,  align: PointerWidth<Target>,  output: InitializedSlot<Visibility, PointerWidth<Target>>};
+    {T,} NonNull<T> { size: PointerWidth<Target>,  align: PointerWidth<Target>,  output: InitializedSlot<Visibility, PointerWidth<Target>>};
+}
+
+// These may be null, or a pointer that upholds the invariants of a reference.
+option_layout! {
+    {'a, T,} &'a T     { size: PointerWidth<Target>, align: PointerWidth<Target>, output: Nullable<Visibility, SharedRef<'a, Visibility, T, Target>, Target> };
+    {'a, T,} &'a mut T { size: PointerWidth<Target>, align: PointerWidth<Target>, output: Nullable<Visibility, UniqueRef<'a, Visibility, T, Target>, Target> };
+}
+
+#[cfg(feature = "alloc")]
+option_layout! {
+    {T: 'static,} alloc::boxed::Box<T> { size: PointerWidth<Target>, align: PointerWidth<Target>, output: Nullable<Visibility, OwnedRef<Visibility, T, Target>, Target> };
+}
+
+macro_rules! option_fn_layout {
+    ($($($A: ident),*;)*) => {
+        $(
+            option_layout! {
+                {R: 'static, $($A: 'static,)*} fn($($A),*) -> R
+                    { size: PointerWidth<Target>, align: PointerWidth<Target>, output: Nullable<Visibility, SharedRef<'static, Visibility, Code<fn($($A),*) -> R>, Target>, Target> };
+                {R: 'static, $($A: 'static,)*} unsafe fn($($A),*) -> R
+                    { size: PointerWidth<Target>, align: PointerWidth<Target>, output: Nullable<Visibility, SharedRef<'static, Visibility, Code<unsafe fn($($A),*) -> R>, Target>, Target> };
+                {R: 'static, $($A: 'static,)*} extern "C" fn($($A),*) -> R
+                    { size: PointerWidth<Target>, align: PointerWidth<Target>, output: Nullable<Visibility, SharedRef<'static, Visibility, Code<extern "C" fn($($A),*) -> R>, Target>, Target> };
+                {R: 'static, $($A: 'static,)*} unsafe extern "C" fn($($A),*) -> R
+                    { size: PointerWidth<Target>, align: PointerWidth<Target>, output: Nullable<Visibility, SharedRef<'static, Visibility, Code<unsafe extern "C" fn($($A),*) -> R>, Target>, Target> };
+            }
+        )*
+    };
+}
+
+option_fn_layout! {
+    ;
+    A;
+    A, B;
+    A, B, C;
+    A, B, C, D;
+    A, B, C, D, E;
+    A, B, C, D, E, F;
+}
@@ -1,24 +1,32 @@
 use crate::stability::*;
 use super::IntoByteLevel;
+use crate::private::bytelevel::ops::{IntoInterior, InteriorOf};
 use crate::private::bytelevel::{
-    slot::{Array, InitializedSlot, SharedRef, UniqueRef},
-    NonZeroSeq, PCons, PNil, ReferenceBytes,
+    slot::{bytes::kind, Array, Bytes, InitializedSlot, NonZeroSlot, OwnedRef, PaddingSlot, SharedRef, UniqueRef},
+    PCons, PNil,
 };
-use crate::private::highlevel::{MaxAlign, MinAlign};
+use crate::private::highlevel::{Extent, MaxAlign, MinAlign, Opaque};
 use crate::private::highlevel::Type;
+use core::marker::PhantomData;
 use crate::private::layout::Layout;
 
 use crate::private::num::*;
-use crate::private::target::PointerWidth;
+use typenum::Const;
+use crate::private::target::{self, PointerWidth, U64Align, U128Align};
 
 use crate::stability::{self, TransmutableInto, TransmutableFrom};
 
 macro_rules! primitive_layout {
     ($($ty: ty { size: $size: ty, align: $align: ty };)*) => {
+        primitive_layout! {
+            $($ty { size: $size, align: $align, kind: kind::Initialized };)*
+        }
+    };
+    ($($ty: ty { size: $size: ty, align: $align: ty, kind: $kind: ty };)*) => {
         $(
             impl Type for $ty {
-                #[doc(hidden)] type ReprAlign  = $align;
-                #[doc(hidden)] type ReprPacked = $align;
+                #[doc(hidden)] type ReprAlign  = MinAlign;
+                #[doc(hidden)] type ReprPacked = MaxAlign;
                 #[doc(hidden)] type HighLevel = Self;
             }
 
@@ -30,12 +38,13 @@ macro_rules! primitive_layout {
                 type Type = Self;
             }
 
-            impl<ReprAlign, ReprPacked, Visibility, Offset> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset> for $ty
+            impl<ReprAlign, ReprPacked, Visibility, Offset, Target> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for $ty
             where
+                Target: target::Target,
                 Offset: Add<$size>,
                 Sum<Offset, $size>: Unsigned,
             {
-                type Output = PCons<InitializedSlot<Visibility, $size>, PNil>;
+                type Output = PCons<Bytes<Visibility, $kind, $size>, PNil>;
                 type Offset = Sum<Offset, $size>;
                 type Align  = $align;
             }
@@ -44,43 +53,81 @@ macro_rules! primitive_layout {
 }
 
 primitive_layout! {
-    u8    { size: U1,           align: U1             };
-    u16   { size: U2,           align: U2             };
-    u32   { size: U4,           align: U4             };
-    u64   { size: U8,           align: U8             };
-    u128  { size: U16,          align: U16            };
-    i8    { size: U1,           align: U1             };
-    i16   { size: U2,           align: U2             };
-    i32   { size: U4,           align: U4             };
-    i64   { size: U8,           align: U8             };
-    i128  { size: U16,          align: U16            };
-    isize { size: PointerWidth, align: PointerWidth   };
-    usize { size: PointerWidth, align: PointerWidth   };
-    f32   { size: U4,           align: U4             };
-    f64   { size: U8,           align: U8             };
+    u8    { size: U1,                    align: U1                    };
+    u16   { size: U2,                    align: U2                    };
+    u32   { size: U4,                    align: U4                    };
+    u64   { size: U8,                    align: U64Align<Target>      };
+    u128  { size: U16,                   align: U128Align<Target>     };
+    i8    { size: U1,                    align: U1                    };
+    i16   { size: U2,                    align: U2                    };
+    i32   { size: U4,                    align: U4                    };
+    i64   { size: U8,                    align: U64Align<Target>      };
+    i128  { size: U16,                   align: U128Align<Target>     };
+    isize { size: PointerWidth<Target>,  align: PointerWidth<Target>  };
+    usize { size: PointerWidth<Target>,  align: PointerWidth<Target>  };
+    f32   { size: U4,                    align: U4                    };
+    f64   { size: U8,                    align: U64Align<Target>      };
+}
+
+primitive_layout! {
+    bool  { size: U1,                    align: U1,                    kind: kind::Bool };
+    char  { size: U4,                    align: U4,                    kind: kind::Char };
 }
 
 use core::sync::atomic::*;
 
-primitive_layout! {
-    AtomicU8    { size: U1,           align: U1             };
-    AtomicU16   { size: U2,           align: U2             };
-    AtomicU32   { size: U4,           align: U4             };
-    AtomicU64   { size: U8,           align: U8             };
-    AtomicUsize { size: PointerWidth, align: PointerWidth   };
-    AtomicI8    { size: U1,           align: U1             };
-    AtomicI16   { size: U2,           align: U2             };
-    AtomicI32   { size: U4,           align: U4             };
-    AtomicI64   { size: U8,           align: U8             };
-    AtomicIsize { size: PointerWidth, align: PointerWidth   };
+// An atomic is an `UnsafeCell` of its value: its bytes are those of the
+// value, but interior mutable.
+macro_rules! atomic_layout {
+    ($($ty: ty { value: $value: ty, align: $align: ty };)*) => {
+        $(
+            impl Type for $ty {
+                #[doc(hidden)] type ReprAlign  = MinAlign;
+                #[doc(hidden)] type ReprPacked = MaxAlign;
+                #[doc(hidden)] type HighLevel = Self;
+            }
+
+            unsafe impl TransmutableFrom for $ty {
+                type Type = Self;
+            }
+
+            unsafe impl TransmutableInto for $ty {
+                type Type = Self;
+            }
+
+            impl<ReprAlign, ReprPacked, Visibility, Offset, Target> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for $ty
+            where
+                Target: target::Target,
+                $value: IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target>,
+                <$value as IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target>>::Output: IntoInterior,
+            {
+                type Output = InteriorOf<<$value as IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target>>::Output>;
+                type Offset = <$value as IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target>>::Offset;
+                type Align  = $align;
+            }
+        )*
+    }
+}
+
+atomic_layout! {
+    AtomicU8    { value: u8,    align: U1                    };
+    AtomicU16   { value: u16,   align: U2                    };
+    AtomicU32   { value: u32,   align: U4                    };
+    AtomicU64   { value: u64,   align: U64Align<Target>      };
+    AtomicUsize { value: usize, align: PointerWidth<Target>  };
+    AtomicI8    { value: i8,    align: U1                    };
+    AtomicI16   { value: i16,   align: U2                    };
+    AtomicI32   { value: i32,   align: U4                    };
+    AtomicI64   { value: i64,   align: U64Align<Target>      };
+    AtomicIsize { value: isize, align: PointerWidth<Target>  };
 }
 
 macro_rules! nonzero_layout {
     ($($ty: ty { size: $size: ty, align: $align: ty };)*) => {
         $(
             impl Type for $ty {
-                #[doc(hidden)] type ReprAlign  = $align;
-                #[doc(hidden)] type ReprPacked = $align;
+                #[doc(hidden)] type ReprAlign  = MinAlign;
+                #[doc(hidden)] type ReprPacked = MaxAlign;
                 #[doc(hidden)] type HighLevel = Self;
             }
 
@@ -92,12 +139,13 @@ macro_rules! nonzero_layout {
                 type Type = Self;
             }
 
-            impl<ReprAlign, ReprPacked, Visibility, Offset> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset> for $ty
+            impl<ReprAlign, ReprPacked, Visibility, Offset, Target> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for $ty
             where
+                Target: target::Target,
                 Offset: Add<$size>,
                 Sum<Offset, $size>: Unsigned,
             {
-                type Output = NonZeroSeq<Visibility, $size, PNil>;
+                type Output = PCons<NonZeroSlot<Visibility, $size>, PNil>;
                 type Offset = Sum<Offset, $size>;
                 type Align  = $align;
             }
@@ -108,43 +156,57 @@ macro_rules! nonzero_layout {
 use core::num::*;
 
 nonzero_layout! {
-    NonZeroU8    { size: U1,           align: U1             };
-    NonZeroU16   { size: U2,           align: U2             };
-    NonZeroU32   { size: U4,           align: U4             };
-    NonZeroU64   { size: U8,           align: U8             };
-    NonZeroU128  { size: U16,          align: U16            };
-    NonZeroI8    { size: U1,           align: U1             };
-    NonZeroI16   { size: U2,           align: U2             };
-    NonZeroI32   { size: U4,           align: U4             };
-    NonZeroI64   { size: U8,           align: U8             };
-    NonZeroI128  { size: U16,          align: U16            };
-    NonZeroIsize { size: PointerWidth, align: PointerWidth   };
-    NonZeroUsize { size: PointerWidth, align: PointerWidth   };
-}
+    NonZeroU8    { size: U1,                    align: U1                    };
+    NonZeroU16   { size: U2,                    align: U2                    };
+    NonZeroU32   { size: U4,                    align: U4                    };
+    NonZeroU64   { size: U8,                    align: U64Align<Target>      };
+    NonZeroU128  { size: U16,                   align: U128Align<Target>     };
+    NonZeroI8    { size: U1,                    align: U1                    };
+    NonZeroI16   { size: U2,                    align: U2                    };
+    NonZeroI32   { size: U4,                    align: U4                    };
+    NonZeroI64   { size: U8,                    align: U64Align<Target>      };
+    NonZeroI128  { size: U16,                   align: U128Align<Target>     };
+    NonZeroIsize { size: PointerWidth<Target>,  align: PointerWidth<Target>  };
+    NonZeroUsize { size: PointerWidth<Target>,  align: PointerWidth<Target>  };
+}
+
+macro_rules! zst_layout {
+    ($({$($G: tt)*} $ty: ty;)*) => {
+        $(
+            #[rustfmt::skip]
+            impl<$($G)*> Type for $ty {
+                #[doc(hidden)] type ReprAlign  = U1;
+                #[doc(hidden)] type ReprPacked = U1;
+                #[doc(hidden)] type HighLevel = Self;
+            }
 
-#[rustfmt::skip]
-impl Type for () {
-    #[doc(hidden)] type ReprAlign  = PointerWidth;
-    #[doc(hidden)] type ReprPacked = PointerWidth;
-    #[doc(hidden)] type HighLevel = Self;
-}
+            unsafe impl<$($G)*> TransmutableFrom for $ty {
+                type Type = Self;
+            }
 
-unsafe impl TransmutableFrom for () {
-    type Type = Self;
-}
+            unsafe impl<$($G)*> TransmutableInto for $ty {
+                type Type = Self;
+            }
 
-unsafe impl TransmutableInto for () {
-    type Type = Self;
+            impl<$($G)* ReprAlign, ReprPacked, Visibility, Offset, Target>
+                IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for $ty
+            where
+                Offset: Unsigned,
+            {
+                type Output = PNil;
+                type Offset = Offset;
+                type Align = U1;
+            }
+        )*
+    };
 }
 
-impl<ReprAlign, ReprPacked, Visibility, Offset> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset> for ()
-where
-    Offset: Unsigned,
-    PointerWidth: Unsigned,
-{
-    type Output = PNil;
-    type Offset = Offset;
-    type Align = PointerWidth;
+use core::marker::PhantomPinned;
+
+zst_layout! {
+    {} ();
+    {T: ?Sized,} PhantomData<T>;
+    {} PhantomPinned;
 }
 
 unsafe impl<'a, T> TransmutableFrom for &'a T
@@ -164,14 +226,15 @@ impl<'a, T> Type for &'a T {
     #[doc(hidden)] type HighLevel = Self;
 }
 
-impl<'a, ReprAlign, ReprPacked, Visibility, Offset, T> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset> for &'a T
+impl<'a, ReprAlign, ReprPacked, Visibility, Offset, Target, T> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for &'a T
 where
-    Offset: Add<PointerWidth>,
-    Sum<Offset, PointerWidth>: Unsigned,
+    Target: target::Target,
+    Offset: Add<PointerWidth<Target>>,
+    Sum<Offset, PointerWidth<Target>>: Unsigned,
 {
-    type Output = PCons<SharedRef<'a, Visibility, T>, PNil>;
-    type Offset = Sum<Offset, PointerWidth>;
-    type Align = PointerWidth;
+    type Output = PCons<SharedRef<'a, Visibility, T, Target>, PNil>;
+    type Offset = Sum<Offset, PointerWidth<Target>>;
+    type Align = PointerWidth<Target>;
 }
 
 unsafe impl<'a, T> TransmutableFrom for &'a mut T
@@ -191,15 +254,16 @@ impl<'a, T> Type for &'a mut T {
     #[doc(hidden)] type HighLevel = Self;
 }
 
-impl<'a, ReprAlign, ReprPacked, Visibility, Offset, T> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset>
+impl<'a, ReprAlign, ReprPacked, Visibility, Offset, Target, T> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target>
     for &'a mut T
 where
-    Offset: Add<PointerWidth>,
-    Sum<Offset, PointerWidth>: Unsigned,
+    Target: target::Target,
+    Offset: Add<PointerWidth<Target>>,
+    Sum<Offset, PointerWidth<Target>>: Unsigned,
 {
-    type Output = PCons<UniqueRef<'a, Visibility, T>, PNil>;
-    type Offset = Sum<Offset, PointerWidth>;
-    type Align = PointerWidth;
+    type Output = PCons<UniqueRef<'a, Visibility, T, Target>, PNil>;
+    type Offset = Sum<Offset, PointerWidth<Target>>;
+    type Align = PointerWidth<Target>;
 }
 
 unsafe impl<T> TransmutableFrom for *const T {
@@ -217,14 +281,15 @@ impl<T> Type for *const T {
     #[doc(hidden)] type HighLevel = Self;
 }
 
-impl<ReprAlign, ReprPacked, Visibility, Offset, T> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset> for *const T
+impl<ReprAlign, ReprPacked, Visibility, Offset, Target, T> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for *const T
 where
-    Offset: Add<PointerWidth>,
-    Sum<Offset, PointerWidth>: Unsigned,
+    Target: target::Target,
+    Offset: Add<PointerWidth<Target>>,
+    Sum<Offset, PointerWidth<Target>>: Unsigned,
 {
-    type Output = ReferenceBytes<Visibility, PNil>;
-    type Offset = Sum<Offset, PointerWidth>;
-    type Align = PointerWidth;
+    type Output = PCons<InitializedSlot<Visibility, PointerWidth<Target>>, PNil>;
+    type Offset = Sum<Offset, PointerWidth<Target>>;
+    type Align = PointerWidth<Target>;
 }
 
 unsafe impl<T> TransmutableFrom for *mut T {
@@ -242,14 +307,15 @@ impl<T> Type for *mut T {
     #[doc(hidden)] type HighLevel = Self;
 }
 
-impl<ReprAlign, ReprPacked, Visibility, Offset, T> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset> for *mut T
+impl<ReprAlign, ReprPacked, Visibility, Offset, Target, T> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for *mut T
 where
-    Offset: Add<PointerWidth>,
-    Sum<Offset, PointerWidth>: Unsigned,
+    Target: target::Target,
+    Offset: Add<PointerWidth<Target>>,
+    Sum<Offset, PointerWidth<Target>>: Unsigned,
 {
-    type Output = ReferenceBytes<Visibility, PNil>;
-    type Offset = Sum<Offset, PointerWidth>;
-    type Align = PointerWidth;
+    type Output = PCons<InitializedSlot<Visibility, PointerWidth<Target>>, PNil>;
+    type Offset = Sum<Offset, PointerWidth<Target>>;
+    type Align = PointerWidth<Target>;
 }
 
 #[rustfmt::skip]
@@ -267,20 +333,218 @@ unsafe impl<T> TransmutableInto for AtomicPtr<T> {
     type Type = Self;
 }
 
-impl<ReprAlign, ReprPacked, Visibility, Offset, T> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset> for AtomicPtr<T>
+impl<ReprAlign, ReprPacked, Visibility, Offset, Target, T> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for AtomicPtr<T>
+where
+    Target: target::Target,
+    *mut T: IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target>,
+    <*mut T as IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target>>::Output: IntoInterior,
+{
+    type Output = InteriorOf<<*mut T as IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target>>::Output>;
+    type Offset = <*mut T as IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target>>::Offset;
+    type Align = PointerWidth<Target>;
+}
+
+use core::ptr::NonNull;
+
+unsafe impl<T> TransmutableFrom for NonNull<T> {
+    type Type = Self;
+}
+
+unsafe impl<T> TransmutableInto for NonNull<T> {
+    type Type = Self;
+}
+
+#[rustfmt::skip]
+impl<T> Type for NonNull<T> {
+    #[doc(hidden)] type ReprAlign  = PointerWidth;
+    #[doc(hidden)] type ReprPacked = PointerWidth;
+    #[doc(hidden)] type HighLevel = Self;
+}
+
+impl<ReprAlign, ReprPacked, Visibility, Offset, Target, T> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for NonNull<T>
+where
+    Target: target::Target,
+    Offset: Add<PointerWidth<Target>>,
+    Sum<Offset, PointerWidth<Target>>: Unsigned,
+{
+    type Output = PCons<NonZeroSlot<Visibility, PointerWidth<Target>>, PNil>;
+    type Offset = Sum<Offset, PointerWidth<Target>>;
+    type Align = PointerWidth<Target>;
+}
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+// A `Box` is modeled as a `'static` reference that owns its referent, so its
+// referent must be `'static`, too.
+#[cfg(feature = "alloc")]
+unsafe impl<T: 'static> TransmutableFrom for Box<T> {
+    type Type = Self;
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<T: 'static> TransmutableInto for Box<T> {
+    type Type = Self;
+}
+
+#[cfg(feature = "alloc")]
+#[rustfmt::skip]
+impl<T: 'static> Type for Box<T> {
+    #[doc(hidden)] type ReprAlign  = PointerWidth;
+    #[doc(hidden)] type ReprPacked = PointerWidth;
+    #[doc(hidden)] type HighLevel = Self;
+}
+
+#[cfg(feature = "alloc")]
+impl<ReprAlign, ReprPacked, Visibility, Offset, Target, T: 'static> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for Box<T>
 where
-    Offset: Add<PointerWidth>,
-    Sum<Offset, PointerWidth>: Unsigned,
+    Target: target::Target,
+    Offset: Add<PointerWidth<Target>>,
+    Sum<Offset, PointerWidth<Target>>: Unsigned,
 {
-    type Output = ReferenceBytes<Visibility, PNil>;
-    type Offset = Sum<Offset, PointerWidth>;
-    type Align = PointerWidth;
+    type Output = PCons<OwnedRef<Visibility, T, Target>, PNil>;
+    type Offset = Sum<Offset, PointerWidth<Target>>;
+    type Align = PointerWidth<Target>;
+}
+
+/// The code that a function pointer of type `F` points to.
+///
+/// Its layout is undefined, so function pointers may be transmuted into
+/// their addresses, but only into function pointers of the very same type.
+pub struct Code<F>(PhantomData<F>);
+
+#[rustfmt::skip]
+impl<F> Type for Code<F> {
+    #[doc(hidden)] type ReprAlign  = MinAlign;
+    #[doc(hidden)] type ReprPacked = MaxAlign;
+    #[doc(hidden)] type HighLevel = Opaque<Self>;
+}
+
+// A function's code occupies at least a byte; a nonzero size also keeps its
+// identity visible to runtime checks, which elide empty segments.
+impl<F> Extent for Code<F> {
+    type Size = U1;
+    type Align = U1;
+
+    // The code is never read, so its layout can never change.
+    const STABLE: bool = true;
+}
+
+unsafe impl<F> TransmutableFrom for Code<F> {
+    type Type = Self;
+}
+
+unsafe impl<F> TransmutableInto for Code<F> {
+    type Type = Self;
+}
+
+macro_rules! fn_layout {
+    ($($($A: ident),*;)*) => {
+        $(
+            fn_layout!(@ fn($($A),*) -> R; $($A),*);
+            fn_layout!(@ unsafe fn($($A),*) -> R; $($A),*);
+            fn_layout!(@ extern "C" fn($($A),*) -> R; $($A),*);
+            fn_layout!(@ unsafe extern "C" fn($($A),*) -> R; $($A),*);
+        )*
+    };
+    (@ $ty: ty; $($A: ident),*) => {
+        #[rustfmt::skip]
+        impl<R: 'static, $($A: 'static),*> Type for $ty {
+            #[doc(hidden)] type ReprAlign  = PointerWidth;
+            #[doc(hidden)] type ReprPacked = PointerWidth;
+            #[doc(hidden)] type HighLevel = Self;
+        }
+
+        unsafe impl<R: 'static, $($A: 'static),*> TransmutableFrom for $ty {
+            type Type = Self;
+        }
+
+        unsafe impl<R: 'static, $($A: 'static),*> TransmutableInto for $ty {
+            type Type = Self;
+        }
+
+        /// A function pointer is a non-null reference to its code.
+        impl<ReprAlign, ReprPacked, Visibility, Offset, Target, R: 'static, $($A: 'static),*>
+            IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for $ty
+        where
+            Target: target::Target,
+            Offset: Add<PointerWidth<Target>>,
+            Sum<Offset, PointerWidth<Target>>: Unsigned,
+        {
+            type Output = PCons<SharedRef<'static, Visibility, Code<Self>, Target>, PNil>;
+            type Offset = Sum<Offset, PointerWidth<Target>>;
+            type Align = PointerWidth<Target>;
+        }
+    };
+}
+
+fn_layout! {
+    ;
+    A;
+    A, B;
+    A, B, C;
+    A, B, C, D;
+    A, B, C, D, E;
+    A, B, C, D, E, F;
 }
 
 use core::cell::{Cell, UnsafeCell};
 
+// The bytes of an `UnsafeCell<T>` are those of `T`, but interior mutable: a
+// shared reference to them may not become a shared reference to bytes that
+// are not (or vice versa). A `Cell<T>` is an `UnsafeCell<T>`.
+macro_rules! cell_layout {
+    ($($cell: ident),*) => {
+        $(
+            #[rustfmt::skip]
+            impl<T> Type for $cell<T> {
+                #[doc(hidden)] type ReprAlign  = MinAlign;
+                #[doc(hidden)] type ReprPacked = MaxAlign;
+                #[doc(hidden)] type HighLevel = Self;
+            }
+
+            unsafe impl<T: TransmutableFrom> TransmutableFrom for $cell<T>
+            where
+                $cell<<T as TransmutableFrom>::Type>: Layout
+            {
+                type Type = $cell<<T as TransmutableFrom>::Type>;
+            }
+
+            unsafe impl<T: TransmutableInto> TransmutableInto for $cell<T>
+            where
+                $cell<<T as TransmutableInto>::Type>: Layout
+            {
+                type Type = $cell<<T as TransmutableInto>::Type>;
+            }
+
+            impl<ReprAlign, ReprPacked, Visibility, Offset, Target, T> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target>
+                for $cell<T>
+            where
+                T: Layout<Visibility, Target>,
+                <T as Layout<Visibility, Target>>::ByteLevel: IntoInterior,
+                Offset: Add<<T as Layout<Visibility, Target>>::Size>,
+                Sum<Offset, <T as Layout<Visibility, Target>>::Size>: Unsigned,
+            {
+                type Output = InteriorOf<<T as Layout<Visibility, Target>>::ByteLevel>;
+                type Offset = Sum<Offset, <T as Layout<Visibility, Target>>::Size>;
+                type Align = <T as Layout<Visibility, Target>>::Align;
+            }
+        )*
+    };
+}
+
+cell_layout!(Cell, UnsafeCell);
+
+/// A type without a defined layout, within a cell.
+impl<T: Extent> Extent for UnsafeCell<T> {
+    type Size = <T as Extent>::Size;
+    type Align = <T as Extent>::Align;
+}
+
+use core::mem::ManuallyDrop;
+
 #[rustfmt::skip]
-impl<T> Type for Cell<T>
+impl<T> Type for ManuallyDrop<T>
 where
     T: Type,
 {
@@ -289,111 +553,88 @@ where
     #[doc(hidden)] type HighLevel =  <T as Type>::HighLevel;
 }
 
-unsafe impl<T: TransmutableFrom> TransmutableFrom for Cell<T>
+unsafe impl<T: TransmutableFrom> TransmutableFrom for ManuallyDrop<T>
 {
     type Type = <T as TransmutableFrom>::Type;
 }
 
-unsafe impl<T: TransmutableInto> TransmutableInto for Cell<T>
+unsafe impl<T: TransmutableInto> TransmutableInto for ManuallyDrop<T>
 {
     type Type = <T as TransmutableInto>::Type;
 }
 
+use core::mem::MaybeUninit;
+
+// The bytes of a `MaybeUninit<T>` need not be initialized, so any bytes may
+// become them; but they may become nothing but uninitialized bytes.
 #[rustfmt::skip]
-impl<T> Type for UnsafeCell<T>
+impl<T> Type for MaybeUninit<T> {
+    #[doc(hidden)] type ReprAlign  = MinAlign;
+    #[doc(hidden)] type ReprPacked = MaxAlign;
+    #[doc(hidden)] type HighLevel = Self;
+}
+
+unsafe impl<T: TransmutableFrom> TransmutableFrom for MaybeUninit<T>
 where
-    T: Type,
+    MaybeUninit<<T as TransmutableFrom>::Type>: Layout
 {
-    #[doc(hidden)] type ReprAlign  = <T as Type>::ReprAlign;
-    #[doc(hidden)] type ReprPacked = <T as Type>::ReprPacked;
-    #[doc(hidden)] type HighLevel =  <T as Type>::HighLevel;
+    type Type = MaybeUninit<<T as TransmutableFrom>::Type>;
 }
 
-unsafe impl<T: TransmutableInto> TransmutableInto for UnsafeCell<T>
+unsafe impl<T: TransmutableInto> TransmutableInto for MaybeUninit<T>
+where
+    MaybeUninit<<T as TransmutableInto>::Type>: Layout
 {
-    type Type = <T as TransmutableInto>::Type;
+    type Type = MaybeUninit<<T as TransmutableInto>::Type>;
 }
 
-unsafe impl<T: TransmutableFrom> TransmutableFrom for UnsafeCell<T>
+impl<ReprAlign, ReprPacked, Visibility, Offset, Target, T> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target>
+    for MaybeUninit<T>
+where
+    T: Layout<Visibility, Target>,
+    Offset: Add<<T as Layout<Visibility, Target>>::Size>,
+    Sum<Offset, <T as Layout<Visibility, Target>>::Size>: Unsigned,
 {
-    type Type = <T as TransmutableFrom>::Type;
+    type Output = PCons<PaddingSlot<Visibility, <T as Layout<Visibility, Target>>::Size>, PNil>;
+    type Offset = Sum<Offset, <T as Layout<Visibility, Target>>::Size>;
+    type Align = <T as Layout<Visibility, Target>>::Align;
 }
 
-macro_rules! array_layout {
-  ($($n: expr, $t: ty);*) => {
-    $(
-        impl<T> Type for [T; $n] {
-            #[doc(hidden)] type ReprAlign  = MinAlign;
-            #[doc(hidden)] type ReprPacked = MaxAlign;
-            #[doc(hidden)] type HighLevel = Self;
-        }
+#[rustfmt::skip]
+impl<T, const N: usize> Type for [T; N] {
+    #[doc(hidden)] type ReprAlign  = MinAlign;
+    #[doc(hidden)] type ReprPacked = MaxAlign;
+    #[doc(hidden)] type HighLevel = Self;
+}
 
-        unsafe impl<T: TransmutableFrom> TransmutableFrom for [T; $n]
-        where
-            [<T as TransmutableFrom>::Type; $n]: Layout
-        {
-            type Type = [<T as TransmutableFrom>::Type; $n];
-        }
+unsafe impl<T: TransmutableFrom, const N: usize> TransmutableFrom for [T; N]
+where
+    [<T as TransmutableFrom>::Type; N]: Layout
+{
+    type Type = [<T as TransmutableFrom>::Type; N];
+}
 
-        unsafe impl<T: TransmutableInto> TransmutableInto for [T; $n]
-        where
-            [<T as TransmutableInto>::Type; $n]: Layout
-        {
-            type Type = [<T as TransmutableInto>::Type; $n];
-        }
+unsafe impl<T: TransmutableInto, const N: usize> TransmutableInto for [T; N]
+where
+    [<T as TransmutableInto>::Type; N]: Layout
+{
+    type Type = [<T as TransmutableInto>::Type; N];
+}
 
-        impl<ReprAlign, ReprPacked, Visibility, Offset, T> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset>
-            for [T; $n]
-        where
-            T: Layout<Visibility>,
-            $t: Mul<<T as Layout<Visibility>>::Size>,
+impl<ReprAlign, ReprPacked, Visibility, Offset, Target, T, const N: usize> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target>
+    for [T; N]
+where
+    T: Layout<Visibility, Target>,
+    Const<N>: ToUnsigned,
+    Length<N>: Mul<<T as Layout<Visibility, Target>>::Size>,
 
-            Offset: Add<Prod<$t, <T as Layout<Visibility>>::Size>>,
-            Sum<Offset, Prod<$t, <T as Layout<Visibility>>::Size>>: Unsigned,
-        {
-            type Output = PCons<Array<Visibility, T, $t>, PNil>;
-            type Offset = Sum<Offset, Prod<$t, <T as Layout<Visibility>>::Size>>;
-            type Align = <T as Layout<Visibility>>::Align;
-        }
-    )*
-  };
-}
-
-array_layout![
-   0,  U0;
-   1,  U1;
-   2,  U2;
-   3,  U3;
-   4,  U4;
-   5,  U5;
-   6,  U6;
-   7,  U7;
-   8,  U8;
-   9,  U9;
-  10, U10;
-  11, U11;
-  12, U12;
-  13, U13;
-  14, U14;
-  15, U15;
-  16, U16;
-  17, U17;
-  18, U18;
-  19, U19;
-  20, U20;
-  21, U21;
-  22, U22;
-  23, U23;
-  24, U24;
-  25, U25;
-  26, U26;
-  27, U27;
-  28, U28;
-  29, U29;
-  30, U30;
-  31, U31;
-  32, U32
-];
+    Offset: Add<Prod<Length<N>, <T as Layout<Visibility, Target>>::Size>>,
+    Sum<Offset, Prod<Length<N>, <T as Layout<Visibility, Target>>::Size>>: Unsigned,
+{
+    type Output = PCons<Array<Visibility, T, Length<N>, Target>, PNil>;
+    type Offset = Sum<Offset, Prod<Length<N>, <T as Layout<Visibility, Target>>::Size>>;
+    type Align = <T as Layout<Visibility, Target>>::Align;
+}
 
 use generic_array::{GenericArray, ArrayLength};
 
@@ -424,17 +665,17 @@ where
     type Type = GenericArray<<T as TransmutableInto>::Type, N>;
 }
 
-impl<ReprAlign, ReprPacked, Visibility, Offset, T, N> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset>
+impl<ReprAlign, ReprPacked, Visibility, Offset, Target, T, N> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target>
     for GenericArray<T, N>
 where
-    T: Layout<Visibility>,
+    T: Layout<Visibility, Target>,
     N: ArrayLength<T>,
-    N: Mul<<T as Layout<Visibility>>::Size>,
+    N: Mul<<T as Layout<Visibility, Target>>::Size>,
 
-    Offset: Add<Prod<N, <T as Layout<Visibility>>::Size>>,
-    Sum<Offset, Prod<N, <T as Layout<Visibility>>::Size>>: Unsigned,
+    Offset: Add<Prod<N, <T as Layout<Visibility, Target>>::Size>>,
+    Sum<Offset, Prod<N, <T as Layout<Visibility, Target>>::Size>>: Unsigned,
 {
-    type Output = PCons<Array<Visibility, T, N>, PNil>;
-    type Offset = Sum<Offset, Prod<N, <T as Layout<Visibility>>::Size>>;
-    type Align = <T as Layout<Visibility>>::Align;
+    type Output = PCons<Array<Visibility, T, N, Target>, PNil>;
+    type Offset = Sum<Offset, Prod<N, <T as Layout<Visibility, Target>>::Size>>;
+    type Align = <T as Layout<Visibility, Target>>::Align;
 }
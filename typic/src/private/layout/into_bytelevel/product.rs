@@ -6,7 +6,7 @@ use crate::private::layout::{padding::PadTo, IntoByteLevel};
 use crate::private::num::{self, Unsigned};
 
 #[rustfmt::skip]
-impl<ReprAlign, ReprPacked, Visibility, Offset> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset> for highlevel::PNil
+impl<ReprAlign, ReprPacked, Visibility, Offset, Target> IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for highlevel::PNil
 where
     ReprAlign: Unsigned,
     Offset: PadTo<ReprAlign> + num::Add<<Offset as PadTo<ReprAlign>>::Output>,
@@ -32,64 +32,73 @@ where
 }
 
 #[rustfmt::skip]
-impl<ReprAlign, ReprPacked, Visibility, Offset, F, R>
-IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset> for highlevel::PCons<F, R>
+impl<ReprAlign, ReprPacked, Visibility, Offset, Target, F, R>
+IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for highlevel::PCons<F, R>
 where
-    F: FieldIntoByteLevel<ReprPacked, Visibility, Offset>,
+    F: FieldIntoByteLevel<ReprPacked, Visibility, Offset, Target>,
     R: IntoByteLevel<num::Maximum<
-        <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset>>::Align,
+        <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset, Target>>::Align,
         ReprAlign,
-    >, ReprPacked, Visibility, <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset>>::Offset>,
+    >, ReprPacked, Visibility, <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset, Target>>::Offset, Target>,
 
-    <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset>>::Output:
+    <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset, Target>>::Output:
         bytelevel::Add<
             <R as IntoByteLevel<
                 num::Maximum<
-                    <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset>>::Align,
+                    <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset, Target>>::Align,
                     ReprAlign,
                 >,
                 ReprPacked,
                 Visibility,
-                <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset>>::Offset,
+                <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset, Target>>::Offset,
+                Target,
             >>::Output,
         >,
 
-    <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset>>::Align:
+    <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset, Target>>::Align:
         num::Max<ReprAlign>,
 
     num::Maximum<
-        <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset>>::Align,
+        <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset, Target>>::Align,
         ReprAlign,
     >: Unsigned,
 {
     type Output =
         bytelevel::Sum<
-            <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset>>::Output,
+            <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset, Target>>::Output,
             <R as IntoByteLevel<
                 num::Maximum<
-                    <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset>>::Align,
+                    <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset, Target>>::Align,
                     ReprAlign,
                 >,
                 ReprPacked,
                 Visibility,
-                <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset>>::Offset,
+                <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset, Target>>::Offset,
+                Target,
             >>::Output,
         >;
 
     type Offset =
         <R as IntoByteLevel<
             num::Maximum<
-                <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset>>::Align,
+                <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset, Target>>::Align,
                 ReprAlign,
             >,
             ReprPacked,
             Visibility,
-            <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset>>::Offset,
+            <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset, Target>>::Offset,
+            Target,
         >>::Offset;
 
     type Align =
-        num::Maximum<
-            <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset>>::Align,
-            ReprAlign,
-        >;
+        <R as IntoByteLevel<
+            num::Maximum<
+                <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset, Target>>::Align,
+                ReprAlign,
+            >,
+            ReprPacked,
+            Visibility,
+            <F as FieldIntoByteLevel<ReprPacked, Visibility, Offset, Target>>::Offset,
+            Target,
+        >>::Align;
 }
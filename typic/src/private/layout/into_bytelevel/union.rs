@@ -0,0 +1,158 @@
+//! Compute the byte-level layout of a union.
+//!
+//! A value of a union may be initialized through any of its fields (or, in
+//! pieces, through several of them). Each byte of a union therefore has the
+//! least restrictive kind and visibility of the corresponding byte of each of
+//! its fields, where each field is padded to the size of the union.
+use super::IntoByteLevel;
+use crate::private::bytelevel::{self, slot::PaddingSlot, Merge, Merged};
+use crate::private::highlevel::{self, Field, Union};
+use crate::private::layout::Layout;
+use crate::private::num::{self, Minimum, Min, RoundUpTo, Unsigned, U0};
+
+/// The size and alignment of the largest and most-aligned fields of a union.
+pub trait FieldsExtent<ReprPacked, Visibility, Target> {
+    /// The size of the largest field.
+    type Size: Unsigned;
+
+    /// The alignment of the most-aligned field.
+    type Align: Unsigned;
+}
+
+impl<ReprPacked, Visibility, Target> FieldsExtent<ReprPacked, Visibility, Target> for highlevel::PNil {
+    type Size = U0;
+    type Align = num::U1;
+}
+
+#[rustfmt::skip]
+impl<ReprPacked, Visibility, Target, V, F, Rest> FieldsExtent<ReprPacked, Visibility, Target>
+for highlevel::PCons<Field<V, F>, Rest>
+where
+    V: Min<Visibility>,
+    F: Layout<Minimum<V, Visibility>, Target>,
+    Rest: FieldsExtent<ReprPacked, Visibility, Target>,
+
+    <F as Layout<Minimum<V, Visibility>, Target>>::Size:
+        num::Max<<Rest as FieldsExtent<ReprPacked, Visibility, Target>>::Size>,
+    num::Maximum<
+        <F as Layout<Minimum<V, Visibility>, Target>>::Size,
+        <Rest as FieldsExtent<ReprPacked, Visibility, Target>>::Size,
+    >: Unsigned,
+
+    ReprPacked: Min<<F as Layout<Minimum<V, Visibility>, Target>>::Align>,
+    Minimum<ReprPacked, <F as Layout<Minimum<V, Visibility>, Target>>::Align>:
+        num::Max<<Rest as FieldsExtent<ReprPacked, Visibility, Target>>::Align>,
+    num::Maximum<
+        Minimum<ReprPacked, <F as Layout<Minimum<V, Visibility>, Target>>::Align>,
+        <Rest as FieldsExtent<ReprPacked, Visibility, Target>>::Align,
+    >: Unsigned,
+{
+    type Size =
+        num::Maximum<
+            <F as Layout<Minimum<V, Visibility>, Target>>::Size,
+            <Rest as FieldsExtent<ReprPacked, Visibility, Target>>::Size,
+        >;
+
+    type Align =
+        num::Maximum<
+            Minimum<ReprPacked, <F as Layout<Minimum<V, Visibility>, Target>>::Align>,
+            <Rest as FieldsExtent<ReprPacked, Visibility, Target>>::Align,
+        >;
+}
+
+/// Merge the byte-level layouts of the fields of a union, each padded to
+/// `Size`.
+pub trait FieldsIntoByteLevel<Visibility, Size, Target> {
+    type Output;
+}
+
+impl<Visibility, Size, Target> FieldsIntoByteLevel<Visibility, Size, Target> for highlevel::PNil {
+    type Output = bytelevel::PNil;
+}
+
+/// The final field, padded to `Size`.
+#[rustfmt::skip]
+impl<Visibility, Size, Target, V, F> FieldsIntoByteLevel<Visibility, Size, Target>
+for highlevel::PCons<Field<V, F>, highlevel::PNil>
+where
+    V: Min<Visibility>,
+    F: Layout<Minimum<V, Visibility>, Target>,
+    Size: num::Sub<<F as Layout<Minimum<V, Visibility>, Target>>::Size>,
+    <F as Layout<Minimum<V, Visibility>, Target>>::ByteLevel:
+        bytelevel::Add<
+            bytelevel::PCons<
+                PaddingSlot<Minimum<V, Visibility>,
+                    num::Diff<Size, <F as Layout<Minimum<V, Visibility>, Target>>::Size>>,
+                bytelevel::PNil,
+            >,
+        >,
+{
+    type Output =
+        bytelevel::Sum<
+            <F as Layout<Minimum<V, Visibility>, Target>>::ByteLevel,
+            bytelevel::PCons<
+                PaddingSlot<Minimum<V, Visibility>,
+                    num::Diff<Size, <F as Layout<Minimum<V, Visibility>, Target>>::Size>>,
+                bytelevel::PNil,
+            >,
+        >;
+}
+
+#[rustfmt::skip]
+impl<Visibility, Size, Target, F, H, T> FieldsIntoByteLevel<Visibility, Size, Target>
+for highlevel::PCons<F, highlevel::PCons<H, T>>
+where
+    highlevel::PCons<F, highlevel::PNil>: FieldsIntoByteLevel<Visibility, Size, Target>,
+    highlevel::PCons<H, T>: FieldsIntoByteLevel<Visibility, Size, Target>,
+    <highlevel::PCons<F, highlevel::PNil> as FieldsIntoByteLevel<Visibility, Size, Target>>::Output:
+        Merge<<highlevel::PCons<H, T> as FieldsIntoByteLevel<Visibility, Size, Target>>::Output>,
+{
+    type Output =
+        Merged<
+            <highlevel::PCons<F, highlevel::PNil> as FieldsIntoByteLevel<Visibility, Size, Target>>::Output,
+            <highlevel::PCons<H, T> as FieldsIntoByteLevel<Visibility, Size, Target>>::Output,
+        >;
+}
+
+/// A union's size is that of its largest field, rounded up to its alignment.
+#[rustfmt::skip]
+impl<ReprAlign, ReprPacked, Visibility, Offset, Target, Fields>
+IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for Union<Fields>
+where
+    Fields: FieldsExtent<ReprPacked, Visibility, Target>,
+
+    <Fields as FieldsExtent<ReprPacked, Visibility, Target>>::Align: num::Max<ReprAlign>,
+    num::Maximum<<Fields as FieldsExtent<ReprPacked, Visibility, Target>>::Align, ReprAlign>: Unsigned,
+
+    <Fields as FieldsExtent<ReprPacked, Visibility, Target>>::Size:
+        RoundUpTo<num::Maximum<<Fields as FieldsExtent<ReprPacked, Visibility, Target>>::Align, ReprAlign>>,
+
+    Fields: FieldsIntoByteLevel<Visibility,
+        <<Fields as FieldsExtent<ReprPacked, Visibility, Target>>::Size
+            as RoundUpTo<num::Maximum<<Fields as FieldsExtent<ReprPacked, Visibility, Target>>::Align, ReprAlign>>>::Output,
+        Target>,
+
+    Offset: num::Add<
+        <<Fields as FieldsExtent<ReprPacked, Visibility, Target>>::Size
+            as RoundUpTo<num::Maximum<<Fields as FieldsExtent<ReprPacked, Visibility, Target>>::Align, ReprAlign>>>::Output>,
+
+    num::Sum<Offset,
+        <<Fields as FieldsExtent<ReprPacked, Visibility, Target>>::Size
+            as RoundUpTo<num::Maximum<<Fields as FieldsExtent<ReprPacked, Visibility, Target>>::Align, ReprAlign>>>::Output,
+    >: Unsigned,
+{
+    type Output =
+        <Fields as FieldsIntoByteLevel<Visibility,
+            <<Fields as FieldsExtent<ReprPacked, Visibility, Target>>::Size
+                as RoundUpTo<num::Maximum<<Fields as FieldsExtent<ReprPacked, Visibility, Target>>::Align, ReprAlign>>>::Output,
+            Target,
+        >>::Output;
+
+    type Offset =
+        num::Sum<Offset,
+            <<Fields as FieldsExtent<ReprPacked, Visibility, Target>>::Size
+                as RoundUpTo<num::Maximum<<Fields as FieldsExtent<ReprPacked, Visibility, Target>>::Align, ReprAlign>>>::Output,
+        >;
+
+    type Align = num::Maximum<<Fields as FieldsExtent<ReprPacked, Visibility, Target>>::Align, ReprAlign>;
+}
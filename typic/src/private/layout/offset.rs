@@ -0,0 +1,41 @@
+//! Compute the offsets of the fields of a product type.
+use super::into_bytelevel::field::FieldIntoByteLevel;
+use super::padding::PaddingNeededForField;
+use crate::private::highlevel::{self, Field, Public};
+use crate::private::num::{self, Unsigned, B1, U0};
+
+/// The offset of the `Index`th field of the product `Self`, where `Offset` is
+/// the index of the byte following the end of the preceeding field, and
+/// `Packed` is an unsigned integer reflecting the minimum packing of the
+/// enclosing type.
+pub trait FieldOffset<Packed, Offset, Index> {
+    type Output: Unsigned;
+}
+
+/// The first field begins after its padding.
+#[rustfmt::skip]
+impl<Packed, Offset, V, F, R> FieldOffset<Packed, Offset, U0>
+for highlevel::PCons<Field<V, F>, R>
+where
+    F: PaddingNeededForField<Public, Offset, Packed>,
+    Offset: num::Add<<F as PaddingNeededForField<Public, Offset, Packed>>::Output>,
+    num::Sum<Offset, <F as PaddingNeededForField<Public, Offset, Packed>>::Output>: Unsigned,
+{
+    type Output = num::Sum<Offset, <F as PaddingNeededForField<Public, Offset, Packed>>::Output>;
+}
+
+/// Any other field begins after the first.
+#[rustfmt::skip]
+impl<Packed, Offset, U, B, F, R> FieldOffset<Packed, Offset, num::UInt<U, B>>
+for highlevel::PCons<F, R>
+where
+    F: FieldIntoByteLevel<Packed, Public, Offset>,
+    num::UInt<U, B>: num::Sub<B1>,
+    R: FieldOffset<Packed,
+        <F as FieldIntoByteLevel<Packed, Public, Offset>>::Offset,
+        num::Sub1<num::UInt<U, B>>>,
+{
+    type Output = <R as FieldOffset<Packed,
+        <F as FieldIntoByteLevel<Packed, Public, Offset>>::Offset,
+        num::Sub1<num::UInt<U, B>>>>::Output;
+}
@@ -0,0 +1,138 @@
+//! Unsigned integers restricted to a range of values.
+use super::into_bytelevel::IntoByteLevel;
+use super::Layout;
+use crate::private::bytelevel::{slot::{bytes::kind, Bytes}, PCons, PNil};
+use crate::private::highlevel::Type;
+use crate::private::num::{Add, Sum, Unsigned};
+use crate::stability::{TransmutableFrom, TransmutableInto};
+use core::fmt;
+use core::marker::PhantomData;
+
+/// An unsigned integer, `T`, whose value is in the inclusive range
+/// `Start..=End`.
+///
+/// Typic understands this restriction: a `Ranged` is transmutable into its
+/// integer, or into any `Ranged` whose range includes its own, but an integer
+/// is only transmutable into a `Ranged` with a runtime check:
+/// ```
+/// use typenum::{U0, U100, U255};
+/// use typic::{self, layout::Ranged, stability::StableABI};
+/// use typic::transmute::{StableTransmuteInto, TryTransmuteInto};
+///
+/// #[typic::repr(transparent)]
+/// #[derive(Debug, PartialEq, StableABI)]
+/// pub struct Percent(pub Ranged<u8, U0, U100>);
+///
+/// let percent = Percent(Ranged::new(42).unwrap());
+/// let byte: u8 = percent.transmute_into();
+/// assert_eq!(byte, 42);
+///
+/// let _: Ranged<u8, U0, U255> = Ranged::<u8, U0, U100>::new(42).unwrap().transmute_into();
+///
+/// assert!(TryTransmuteInto::<Percent>::try_transmute_into(100u8).is_ok());
+/// assert!(TryTransmuteInto::<Percent>::try_transmute_into(101u8).is_err());
+/// ```
+///
+/// ```compile_fail
+/// # use typenum::{U0, U100};
+/// # use typic::{layout::Ranged, transmute::StableTransmuteInto};
+/// let _: Ranged<u8, U0, U100> = 42u8.transmute_into(); // Compile Error!
+/// ```
+///
+/// The range bounds the integer's unsigned value, in the byte order of the
+/// target.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ranged<T, Start, End> {
+    value: T,
+    range: PhantomData<(Start, End)>,
+}
+
+impl<T, Start, End> Ranged<T, Start, End>
+where
+    T: Integer,
+    Start: Unsigned,
+    End: Unsigned,
+{
+    /// `value`, if it is in the range `Start..=End`.
+    pub fn new(value: T) -> Option<Self> {
+        if (Start::U128..=End::U128).contains(&value.to_u128()) {
+            Some(Ranged { value, range: PhantomData })
+        } else {
+            None
+        }
+    }
+
+    /// The integer.
+    pub fn get(self) -> T {
+        self.value
+    }
+}
+
+impl<T: fmt::Debug, Start, End> fmt::Debug for Ranged<T, Start, End> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+/// An unsigned integer type, which may be [`Ranged`].
+pub trait Integer: Copy + private::Sealed {
+    #[doc(hidden)]
+    fn to_u128(self) -> u128;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! integer {
+    ($($ty: ty),*) => {
+        $(
+            impl private::Sealed for $ty {}
+
+            impl Integer for $ty {
+                fn to_u128(self) -> u128 {
+                    self as u128
+                }
+            }
+        )*
+    };
+}
+
+integer!(u8, u16, u32, u64, u128, usize);
+
+#[rustfmt::skip]
+impl<T, Start, End> Type for Ranged<T, Start, End>
+where
+    T: Type,
+{
+    #[doc(hidden)] type ReprAlign  = <T as Type>::ReprAlign;
+    #[doc(hidden)] type ReprPacked = <T as Type>::ReprPacked;
+    #[doc(hidden)] type HighLevel = Self;
+}
+
+unsafe impl<T, Start, End> TransmutableFrom for Ranged<T, Start, End>
+where
+    Self: Layout,
+{
+    type Type = Self;
+}
+
+unsafe impl<T, Start, End> TransmutableInto for Ranged<T, Start, End>
+where
+    Self: Layout,
+{
+    type Type = Self;
+}
+
+impl<ReprAlign, ReprPacked, Visibility, Offset, Target, T, Start, End>
+    IntoByteLevel<ReprAlign, ReprPacked, Visibility, Offset, Target> for Ranged<T, Start, End>
+where
+    T: Integer + Layout<Visibility, Target>,
+    Offset: Add<<T as Layout<Visibility, Target>>::Size>,
+    Sum<Offset, <T as Layout<Visibility, Target>>::Size>: Unsigned,
+{
+    type Output = PCons<Bytes<Visibility, kind::Range<Start, End>, <T as Layout<Visibility, Target>>::Size>, PNil>;
+    type Offset = Sum<Offset, <T as Layout<Visibility, Target>>::Size>;
+    type Align = <T as Layout<Visibility, Target>>::Align;
+}
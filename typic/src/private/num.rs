@@ -1,7 +1,10 @@
 //! Type-level numbers.
 
 pub use core::ops::*;
-pub use typenum::{self, consts::*, operator_aliases::*, type_operators::*, uint::*, Unsigned};
+pub use typenum::{self, consts::*, operator_aliases::*, type_operators::*, uint::*, Bit, Unsigned};
+
+mod length;
+pub use length::{Length, ToUnsigned};
 
 pub trait SaturatingSub<RHS> {
     type Output;
@@ -0,0 +1,43 @@
+//! The type-level numbers of array lengths.
+//!
+//! Stable Rust cannot compute a type from a `const` parameter, so each length
+//! is listed, by `build.rs`: every `N` in `0..=4096`, and every power of two
+//! up to `2^32` that fits in the target's `usize`. Lengths up to 1024 are
+//! typenum's `U<N>`; each longer length is defined in terms of `N / 2`.
+use super::{UInt, Unsigned, B0, B1};
+use typenum::{Const, U};
+
+/// Maps a `const N: usize` to its type-level number.
+#[diagnostic::on_unimplemented(
+    message = "typic does not know the length `{Self}`",
+    note = "typic supports arrays of up to 4096 elements, and of any power of two elements up to 2^32 (2^31 on 32-bit targets); consider `GenericArray`",
+)]
+pub trait ToUnsigned {
+    type Output: Unsigned;
+}
+
+/// The type-level number of the `usize` `N`.
+pub type Length<const N: usize> = <Const<N> as ToUnsigned>::Output;
+
+macro_rules! typenum {
+    ($($n: literal;)*) => {
+        $(
+            impl ToUnsigned for Const<$n> {
+                type Output = U<$n>;
+            }
+        )*
+    };
+}
+
+macro_rules! length {
+    ($($(#[$attr: meta])* $n: literal = $half: literal, $bit: ident;)*) => {
+        $(
+            $(#[$attr])*
+            impl ToUnsigned for Const<$n> {
+                type Output = UInt<Length<$half>, $bit>;
+            }
+        )*
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/length.rs"));
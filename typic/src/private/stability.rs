@@ -12,21 +12,64 @@
 use crate::layout::Layout;
 use crate::transmute::{self, neglect, TransmuteFrom, TransmuteInto};
 use crate::private::highlevel::Public;
+use crate::private::transmute::{
+    from_type::FromType, AlwaysValid, Static, Unenforced, Unstable, Variant,
+};
 
 /// Implements [`TransmutableInto`] and [`TransmutableFrom`] for a
 /// type, using that type as its own ABI bound.
 ///
 /// You must not make any changes to this type that narrows the
 /// visibility of its fields or changes its layout.
+///
+/// To promise less, declare other ABI bounds with
+/// `#[stable_abi(into = "...", from = "...")]`. The type must be soundly
+/// transmutable into its `into` bound and from its `from` bound:
+/// ```
+/// use typic::{self, stability::*};
+///
+/// #[typic::repr(C)]
+/// #[derive(StableABI)]
+/// #[stable_abi(into = "[u8; 2]", from = "[u8; 4]")]
+/// pub struct Header { pub tag: u8, pub len: u8 }
+/// ```
+/// ```compile_fail
+/// use typic::{self, stability::*};
+///
+/// #[typic::repr(C)]
+/// #[derive(StableABI)]
+/// #[stable_abi(into = "[u8; 4]")] // Compile Error!
+/// pub struct Header { pub tag: u8, pub len: u8 }
+/// ```
 pub use typic_derive::StableABI;
 
+/// Implemented if `T` is soundly transmutable into `Self`, regardless of
+/// their ABI bounds. Used by [`StableABI`] to check declared ABI bounds.
+#[doc(hidden)]
+pub trait AbiBound<T> {}
+
+impl<T, U> AbiBound<T> for U
+where
+    U: FromType<T, Variant, Static, Unenforced, Unstable, AlwaysValid>,
+{}
+
 /// Assert that `Self` is always transmutable into `Type`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not have a stable ABI",
+    label = "`{Self}` does not have a stable ABI",
+    note = "consider `#[derive(StableABI)]`, or neglecting stability with `neglect::Stability`",
+)]
 pub unsafe trait TransmutableInto
 {
     type Type: Layout;
 }
 
 /// Assert that `Self` is always transmutable from `Type`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not have a stable ABI",
+    label = "`{Self}` does not have a stable ABI",
+    note = "consider `#[derive(StableABI)]`, or neglecting stability with `neglect::Stability`",
+)]
 pub unsafe trait TransmutableFrom
 {
     type Type: Layout;
@@ -1,15 +1,118 @@
-//! Type-level information about the compilation target.
+//! Type-level descriptions of compilation targets.
+//!
+//! Layouts are computed for the [`Host`] target, unless another target is
+//! given. Checking a transmutation against other targets, too (with
+//! [`assert_transmutable_on!`][crate::assert_transmutable_on]), guards
+//! portable types (such as wire formats) against layouts that differ between
+//! them, from a single build.
+//!
+//! The layouts of types without a defined representation are those of the
+//! host.
 
+use crate::private::bytelevel::slot::bytes::kind::Ones;
 use crate::private::num::*;
+use core::mem;
 
-/// The pointer width, in bytes, of the target platform.
-#[cfg(target_pointer_width = "8")]
-pub type PointerWidth = U1;
-#[cfg(target_pointer_width = "16")]
-pub type PointerWidth = U2;
-#[cfg(target_pointer_width = "32")]
-pub type PointerWidth = U4;
-#[cfg(target_pointer_width = "64")]
-pub type PointerWidth = U8;
-#[cfg(target_pointer_width = "128")]
-pub type PointerWidth = U16;
+/// A description of a compilation target.
+pub trait Target {
+    /// The size and alignment, in bytes, of pointers, `usize` and `isize`.
+    type PointerWidth: Unsigned + Ones;
+
+    /// The byte order of multi-byte values.
+    type Endian: Endian;
+
+    /// The alignment, in bytes, of `u64`, `i64` and `f64`.
+    type U64Align: Unsigned;
+
+    /// The alignment, in bytes, of `u128` and `i128`.
+    type U128Align: Unsigned;
+}
+
+/// The byte order of a target.
+///
+/// Typic constrains the values of multi-byte integers as a whole, so its
+/// static checks never depend on byte order; it is needed only to read
+/// values at runtime.
+pub trait Endian {
+    /// Whether the least significant byte comes first.
+    const LITTLE: bool;
+}
+
+/// The least significant byte comes first.
+pub struct LittleEndian;
+
+/// The most significant byte comes first.
+pub struct BigEndian;
+
+impl Endian for LittleEndian {
+    const LITTLE: bool = true;
+}
+
+impl Endian for BigEndian {
+    const LITTLE: bool = false;
+}
+
+/// The target being compiled for.
+pub struct Host;
+
+impl Target for Host {
+    type PointerWidth = Length<{ mem::size_of::<usize>() }>;
+
+    #[cfg(target_endian = "little")]
+    type Endian = LittleEndian;
+    #[cfg(target_endian = "big")]
+    type Endian = BigEndian;
+
+    type U64Align = Length<{ mem::align_of::<u64>() }>;
+    type U128Align = Length<{ mem::align_of::<u128>() }>;
+}
+
+macro_rules! target {
+    ($($(#[$attr: meta])* $name: ident {
+        pointer_width: $width: ty,
+        endian: $endian: ty,
+        u64_align: $u64: ty,
+        u128_align: $u128: ty $(,)?
+    })*) => {
+        $(
+            $(#[$attr])*
+            pub struct $name;
+
+            impl Target for $name {
+                type PointerWidth = $width;
+                type Endian = $endian;
+                type U64Align = $u64;
+                type U128Align = $u128;
+            }
+        )*
+    };
+}
+
+target! {
+    /// 64-bit ARM (e.g., `aarch64-unknown-linux-gnu`).
+    Aarch64 { pointer_width: U8, endian: LittleEndian, u64_align: U8, u128_align: U16 }
+
+    /// 32-bit ARM (e.g., `armv7-unknown-linux-gnueabihf`).
+    Armv7 { pointer_width: U4, endian: LittleEndian, u64_align: U8, u128_align: U8 }
+
+    /// 32-bit x86 (e.g., `i686-unknown-linux-gnu`).
+    I686 { pointer_width: U4, endian: LittleEndian, u64_align: U4, u128_align: U16 }
+
+    /// Big-endian 64-bit PowerPC (e.g., `powerpc64-unknown-linux-gnu`).
+    Powerpc64 { pointer_width: U8, endian: BigEndian, u64_align: U8, u128_align: U16 }
+
+    /// 32-bit WebAssembly (e.g., `wasm32-unknown-unknown`).
+    Wasm32 { pointer_width: U4, endian: LittleEndian, u64_align: U8, u128_align: U16 }
+
+    /// 64-bit x86 (e.g., `x86_64-unknown-linux-gnu`).
+    X86_64 { pointer_width: U8, endian: LittleEndian, u64_align: U8, u128_align: U16 }
+}
+
+/// The pointer width, in bytes, of `Tgt`.
+pub type PointerWidth<Tgt = Host> = <Tgt as Target>::PointerWidth;
+
+/// The alignment, in bytes, of `u64` on `Tgt`.
+pub type U64Align<Tgt = Host> = <Tgt as Target>::U64Align;
+
+/// The alignment, in bytes, of `u128` on `Tgt`.
+pub type U128Align<Tgt = Host> = <Tgt as Target>::U128Align;
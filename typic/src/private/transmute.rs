@@ -1,9 +1,23 @@
-use core::mem;
+use crate::layout::{Describe, Kind};
+use core::{fmt, mem};
+use core::marker::PhantomData;
 
 /// Allow bit-validity to expand.
 pub struct Variant;
 /// Constrain bit-validity to be equal.
 pub struct Invariant;
+/// Allow bit-validity to expand, but not private bytes to become public;
+/// i.e., the bytes are those of a referent that may only be read.
+pub struct ReadOnly;
+/// The bytes are shared, so must keep their interior mutability. Bytes that
+/// are not interior mutable are `ReadOnly` if `Variance` is `Variant`, and
+/// `Invariant` otherwise; those that are, `Invariant`.
+pub struct Aliased<Variance>(PhantomData<Variance>);
+/// The bytes are those of a unique referent that became a shared referent,
+/// so their interior mutability may change. Bytes that become interior
+/// mutable may be written through the shared referent, so are `Invariant`;
+/// the rest, `ReadOnly`.
+pub struct Exclusive;
 
 /// Alignment of pointers is statically checked.
 pub struct Static;
@@ -22,6 +36,12 @@ pub struct MaybeInvalid;
 
 pub mod neglect;
 
+pub mod explain;
+
+pub mod runtime;
+
+pub mod slice;
+
 #[rustfmt::skip]
 pub mod from_type;
 
@@ -54,7 +74,7 @@ pub struct Unstable;
 /// [`safe_transmute`]: safe_transmute
 /// [soundness]: crate::transmute::unsafe_transmutation#when-is-a-transmutation-sound
 /// [safety]: crate::transmute::safe_transmutation
-pub unsafe trait TransmuteInto<U, O = ()>: UnsafeTransmuteInto<U>
+pub unsafe trait TransmuteInto<U, O = ()>: UnsafeTransmuteInto<U, O>
 where
     O: neglect::TransmuteOptions,
 {
@@ -68,7 +88,7 @@ unsafe impl<T, U, O> TransmuteInto<U, O> for T
 where
     U: TransmuteFrom<T, O>,
     O: neglect::TransmuteOptions,
-    Self: UnsafeTransmuteInto<U>,
+    Self: UnsafeTransmuteInto<U, O>,
 {
     #[inline(always)]
     fn transmute_into(self) -> U {
@@ -160,6 +180,175 @@ where
     }
 }
 
+/// Compiles only if `T` is [safely][TransmuteInto] transmutable into `U` on
+/// `Target`, neglecting the options `O`.
+///
+/// See [`assert_transmutable_on!`][crate::assert_transmutable_on].
+#[doc(hidden)]
+pub fn transmutable_on<T, U, O, Target>()
+where
+    U: from_type::FromType<T,
+        Variant,
+        <O as neglect::UnsafeTransmuteOptions>::Alignment,
+        <O as neglect::UnsafeTransmuteOptions>::Transparency,
+        <O as neglect::UnsafeTransmuteOptions>::Stability,
+        <O as neglect::UnsafeTransmuteOptions>::Validity,
+        Target,
+      >,
+    O: neglect::TransmuteOptions,
+{}
+
+/// A ***safe*** and ***sound*** value-to-value conversion, which checks at
+/// runtime that the value is a valid instance of `U`.
+/// The reciprocal of [`TryTransmuteFrom`].
+///
+/// See [`TryTransmuteFrom`].
+///
+/// [`TryTransmuteFrom`]: TryTransmuteFrom
+pub unsafe trait TryTransmuteInto<U, O = ()>: Sized
+where
+    O: neglect::TransmuteOptions,
+{
+    /// Reinterprets the bits of `self` as type `U`, if they are a valid
+    /// instance of `U`.
+    fn try_transmute_into(self) -> Result<U, ValidityError>;
+}
+
+unsafe impl<T, U, O> TryTransmuteInto<U, O> for T
+where
+    U: TryTransmuteFrom<T, O>,
+    O: neglect::TransmuteOptions,
+{
+    #[inline(always)]
+    fn try_transmute_into(self) -> Result<U, ValidityError> {
+        U::try_transmute_from(self)
+    }
+}
+
+/// A ***safe*** and ***sound*** value-to-value conversion, which checks at
+/// runtime that the value is a valid instance of `Self`.
+/// The reciprocal of [`TryTransmuteInto`].
+///
+/// `TryTransmuteFrom<T>` is implemented for `U` when `T` would be
+/// [soundly][soundness] and [safely][safety] transmutable into `U`, were it
+/// not for the [validity][crate::transmute::neglect::Validity] of some
+/// values of `T` as instances of `U`. Only the bytes whose validity `U`
+/// restricts further than `T` are checked:
+/// ```
+/// use core::num::NonZeroU8;
+/// use typic::{self, stability::StableABI, transmute::TryTransmuteInto};
+///
+/// #[typic::repr(u8)]
+/// #[derive(Debug, PartialEq, StableABI)]
+/// pub enum Flag { Off = 0, On = 1 }
+///
+/// #[typic::repr(C)]
+/// #[derive(Debug, PartialEq, StableABI)]
+/// pub struct Header { pub flag: Flag, pub len: NonZeroU8 }
+///
+/// let header = TryTransmuteInto::<Header>::try_transmute_into([1u8, 16]);
+/// assert_eq!(header, Ok(Header { flag: Flag::On, len: NonZeroU8::new(16).unwrap() }));
+///
+/// let error = TryTransmuteInto::<Header>::try_transmute_into([2u8, 16]).unwrap_err();
+/// assert_eq!(error.offset(), 0);
+///
+/// let error = TryTransmuteInto::<Header>::try_transmute_into([0u8, 0]).unwrap_err();
+/// assert_eq!(error.offset(), 1);
+/// assert_eq!(error.to_string(), "invalid bytes at offset 1: expected a value in 1..=255");
+/// ```
+///
+/// Transmutations that are invalid regardless of the value are still
+/// rejected at compile time; e.g., padding may not become initialized bytes:
+/// ```compile_fail
+/// # use typic::{self, stability::StableABI, transmute::TryTransmuteInto};
+/// #[typic::repr(C)]
+/// #[derive(StableABI)]
+/// pub struct Padded(pub u8, pub u16);
+///
+/// let _ = TryTransmuteInto::<u32>::try_transmute_into(Padded(0, 0)); // Compile Error!
+/// ```
+///
+/// The validity of the referents of references is never checked at runtime;
+/// it must be statically guaranteed.
+///
+/// [soundness]: crate::transmute::unsafe_transmutation#when-is-a-transmutation-sound
+/// [safety]: crate::transmute::safe_transmutation
+pub unsafe trait TryTransmuteFrom<T, O = ()>: Sized
+where
+    O: neglect::TransmuteOptions,
+{
+    /// Reinterprets the bits of `from` as type `Self`, if they are a valid
+    /// instance of `Self`.
+    fn try_transmute_from(from: T) -> Result<Self, ValidityError>;
+}
+
+unsafe impl<T, U, O> TryTransmuteFrom<T, O> for U
+where
+    T: Describe,
+    U: Describe,
+    U: from_type::FromType<T,
+        Variant,
+        Static,
+        Enforced,
+        <O as neglect::UnsafeTransmuteOptions>::Stability,
+        MaybeInvalid,
+      >,
+    O: neglect::TransmuteOptions,
+{
+    #[inline]
+    fn try_transmute_from(from: T) -> Result<U, ValidityError> {
+        // Everything but validity is statically checked.
+        let options = runtime::Options {
+            variant: true,
+            aliased: false,
+            referent: false,
+            alignment: true,
+            transparency: true,
+            stable: false,
+            validity: false,
+        };
+        let value = &from as *const T as *const u8;
+
+        unsafe { runtime::check(&T::DESCRIPTION, &U::DESCRIPTION, options, Some(value)) }
+            .map_err(|divergence| ValidityError {
+                offset: divergence.offset,
+                expected: divergence.destination,
+            })?;
+
+        unsafe {
+            let to = mem::transmute_copy(&from);
+            mem::forget(from);
+            Ok(to)
+        }
+    }
+}
+
+/// The error returned by [`TryTransmuteFrom`] and [`TryTransmuteInto`] when
+/// the bytes of a value are not a valid instance of the destination type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValidityError {
+    offset: usize,
+    expected: Kind,
+}
+
+impl ValidityError {
+    /// The offset of the first invalid bytes.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The kind of bytes the destination type expected at that offset.
+    pub fn expected(&self) -> Kind {
+        self.expected
+    }
+}
+
+impl fmt::Display for ValidityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid bytes at offset {}: expected {}", self.offset, self.expected)
+    }
+}
+
 /// A ***sound*** value-to-value conversion.
 /// The reciprocal of [`UnsafeTransmuteFrom`].
 ///
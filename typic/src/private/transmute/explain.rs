@@ -0,0 +1,305 @@
+//! Explain, at runtime, why a transmutation is (or is not) accepted.
+//!
+//! The explainer [walks][super::runtime] the source and destination layouts
+//! side by side, and stops at the first pair of bytes that the rules of
+//! [`FromLayout`][super::from_layout::FromLayout] reject.
+use super::neglect::UnsafeTransmuteOptions;
+use super::runtime::{
+    self, CheckAlignment, CheckStability, CheckTransparency, CheckValidity, Options,
+};
+use super::{Stable, Unstable};
+use crate::layout::{Describe, Kind};
+use crate::stability::{TransmutableFrom, TransmutableInto};
+use core::any::type_name;
+use core::fmt;
+
+/// Explain whether `T` is transmutable into `U`, when the static checks `O`
+/// are neglected.
+///
+/// ```
+/// use typic::{self, explain, transmute::neglect};
+///
+/// #[typic::repr(C)]
+/// pub struct Padded(pub u8, pub u16);
+///
+/// let explanation = explain::<Padded, u32, neglect::Stability>();
+/// assert!(!explanation.is_transmutable());
+/// // "`Padded` is not transmutable into `u32`: at offset 1, padding would
+/// // become initialized bytes"
+/// assert!(explanation
+///     .to_string()
+///     .ends_with("at offset 1, padding would become initialized bytes"));
+///
+/// assert!(explain::<u32, Padded, neglect::Stability>().is_transmutable());
+/// ```
+///
+/// If the stability of layouts is not neglected, both types must have a
+/// [stable ABI][crate::stability::StableABI]; their ABI bounds are explained,
+/// too:
+/// ```compile_fail
+/// use typic::{self, explain};
+///
+/// #[typic::repr(C)]
+/// pub struct Padded(pub u8, pub u16);
+///
+/// let _ = explain::<u32, Padded, ()>(); // Compile Error!
+/// ```
+///
+/// The explainer mirrors the checks performed on
+/// [`TransmuteInto`][super::TransmuteInto] and
+/// [`UnsafeTransmuteInto`][super::UnsafeTransmuteInto], with one exception:
+/// it does not check that the referents of references have stable ABIs.
+pub fn explain<T, U, O>() -> Explanation
+where
+    T: Describe,
+    U: Describe,
+    O: UnsafeTransmuteOptions,
+    O::Alignment: CheckAlignment,
+    O::Transparency: CheckTransparency,
+    O::Stability: CheckStability + CheckBounds<T, U>,
+    O::Validity: CheckValidity,
+{
+    let options = Options::new::<O>();
+    let divergence = unsafe { runtime::check(&T::DESCRIPTION, &U::DESCRIPTION, options, None) }
+        .and_then(|()| <O::Stability as CheckBounds<T, U>>::bounds(options))
+        .err();
+
+    Explanation {
+        source: type_name::<T>(),
+        destination: type_name::<U>(),
+        divergence,
+    }
+}
+
+/// The result of [`explain`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    source: &'static str,
+    destination: &'static str,
+    divergence: Option<Divergence>,
+}
+
+impl Explanation {
+    /// Whether the source type is transmutable into the destination type.
+    pub fn is_transmutable(&self) -> bool {
+        self.divergence.is_none()
+    }
+
+    /// The first point at which the source type is not transmutable into the
+    /// destination type, if any.
+    pub fn divergence(&self) -> Option<Divergence> {
+        self.divergence
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.divergence {
+            None => write!(
+                f,
+                "`{}` is transmutable into `{}`",
+                self.source, self.destination
+            ),
+            Some(divergence) => write!(
+                f,
+                "`{}` is not transmutable into `{}`: {}",
+                self.source, self.destination, divergence
+            ),
+        }
+    }
+}
+
+/// The first point at which a source layout is not transmutable into a
+/// destination layout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Divergence {
+    /// The offset at which the layouts diverge. If the divergence is
+    /// [behind a reference][Location::Referent], this is the offset of the
+    /// reference.
+    pub offset: usize,
+    /// The rule that was violated.
+    pub reason: Reason,
+    /// The kind of the source's bytes at the divergence, if any remain.
+    pub source: Option<Kind>,
+    /// The kind of the destination's bytes at the divergence.
+    pub destination: Kind,
+    /// Where the divergence was found.
+    pub location: Location,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "at offset {}", self.offset)?;
+        match self.location {
+            Location::Value => {}
+            Location::Referent => f.write_str(" (behind a reference)")?,
+            Location::AbiBound => f.write_str(" of their ABI bounds")?,
+        }
+        f.write_str(", ")?;
+
+        let destination = self.destination;
+        let source = match self.source {
+            Some(source) => source,
+            None => return f.write_str("the destination is larger than the source"),
+        };
+
+        match self.reason {
+            Reason::Padding | Reason::Size => {
+                write!(f, "{} would become {}", source, destination)
+            }
+            Reason::Validity => write!(f, "{} may not be valid as {}", source, destination),
+            Reason::Invariance => write!(
+                f,
+                "{} would become {}, but the bytes of a writable referent must keep their validity",
+                source, destination
+            ),
+            Reason::PrivateIntoPublic => write!(
+                f,
+                "private {} would become public {}",
+                source, destination
+            ),
+            Reason::IntoPrivate => write!(
+                f,
+                "{} would become private {}",
+                source, destination
+            ),
+            Reason::InteriorMutability => write!(
+                f,
+                "{} would become {}, but the bytes of a shared referent must keep their \
+                 interior mutability",
+                source, destination
+            ),
+            Reason::Mutability => f.write_str("a shared reference would become a unique reference"),
+            Reason::Ownership => f.write_str("a borrowed reference would become a `Box`"),
+            Reason::Deallocation => match (source, destination) {
+                (
+                    Kind::Reference { referent: source, .. },
+                    Kind::Reference { referent: destination, .. },
+                ) => write!(
+                    f,
+                    "a `Box` would free its referent of size {} and alignment {} \
+                     as if it had size {} and alignment {}",
+                    source.size(),
+                    source.align(),
+                    destination.size(),
+                    destination.align()
+                ),
+                _ => f.write_str("a `Box` would free its referent with the wrong layout"),
+            },
+            Reason::Alignment => match (source, destination) {
+                (
+                    Kind::Reference { referent: source, .. },
+                    Kind::Reference { referent: destination, .. },
+                ) => write!(
+                    f,
+                    "a referent aligned to {} would become a referent aligned to {}",
+                    source.align(),
+                    destination.align()
+                ),
+                _ => f.write_str("the destination's referent is more strictly aligned"),
+            },
+            Reason::Opaque => write!(
+                f,
+                "{} would become {}, but a type without a defined layout may only become itself",
+                source, destination
+            ),
+            Reason::Stability => write!(
+                f,
+                "{} has no defined layout, so it may only become itself if \
+                 `neglect::Stability` is given",
+                source
+            ),
+        }
+    }
+}
+
+/// The rule violated at a [`Divergence`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reason {
+    /// Possibly-uninitialized bytes (e.g., padding) would become bytes that
+    /// must be initialized.
+    Padding,
+    /// Bytes of a private field would become bytes of a public field of a
+    /// referent.
+    PrivateIntoPublic,
+    /// Bytes would become bytes of a private field, whose invariants may be
+    /// violated.
+    IntoPrivate,
+    /// A referent would become a more strictly aligned referent.
+    Alignment,
+    /// The destination is larger than the source.
+    Size,
+    /// Some values of the source bytes are not valid for the destination
+    /// bytes.
+    Validity,
+    /// Bytes of a referent that may be written (e.g., that of a unique
+    /// reference, or the bytes of a cell) would become bytes of a different
+    /// validity, through which invalid values could be observed or written.
+    Invariance,
+    /// Bytes of a shared referent would gain or lose interior mutability
+    /// (i.e., an `UnsafeCell`), so bytes that one reference observes as
+    /// immutable could be mutated through another.
+    InteriorMutability,
+    /// A shared reference would become a unique reference.
+    Mutability,
+    /// A borrowed reference would become a `Box`, which would free memory it
+    /// does not own.
+    Ownership,
+    /// A `Box` would become a `Box` of a referent with a different size or
+    /// alignment, which would free its memory with the wrong layout.
+    Deallocation,
+    /// A type without a defined layout would become another type.
+    Opaque,
+    /// A type without a defined layout would become itself, but the
+    /// stability of layouts is not neglected.
+    Stability,
+}
+
+/// Where a [`Divergence`] was found.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Location {
+    /// In the source and destination types themselves.
+    Value,
+    /// In the referents of a reference in the source and destination types.
+    Referent,
+    /// In the ABI bounds of the source and destination types; i.e., the
+    /// layouts of the types themselves are compatible, but their declared
+    /// stable ABIs are not.
+    AbiBound,
+}
+
+/// If the stability of layouts is enforced, are the ABI bounds of `T` and
+/// `U` compatible?
+#[doc(hidden)]
+pub trait CheckBounds<T, U> {
+    fn bounds(options: Options) -> Result<(), Divergence>;
+}
+
+impl<T, U> CheckBounds<T, U> for Unstable {
+    fn bounds(_: Options) -> Result<(), Divergence> {
+        Ok(())
+    }
+}
+
+impl<T, U> CheckBounds<T, U> for Stable
+where
+    T: TransmutableInto,
+    U: TransmutableFrom,
+    <T as TransmutableInto>::Type: Describe,
+    <U as TransmutableFrom>::Type: Describe,
+{
+    fn bounds(options: Options) -> Result<(), Divergence> {
+        unsafe {
+            runtime::check(
+                &<T as TransmutableInto>::Type::DESCRIPTION,
+                &<U as TransmutableFrom>::Type::DESCRIPTION,
+                Options { stable: false, ..options },
+                None,
+            )
+        }
+        .map_err(|divergence| Divergence {
+            location: Location::AbiBound,
+            ..divergence
+        })
+    }
+}
@@ -2,12 +2,15 @@ use crate::private::bytelevel::{
     self as blv,
     slot::{Pub, Priv},
     slot::{bytes::kind, *},
-    PCons, PNil, ReferenceBytes,
+    coproduct::{Find, Found},
+    CCons, CNil, PCons, PNil, ReferenceBytes, Variant as Variation,
 };
 use crate::private::layout::{Layout, AlignedTo};
 use crate::private::num::{self, UInt, UTerm};
+use crate::private::target;
+use crate::internal::Public;
 use super::from_type::FromType;
-use super::{Variant, Invariant, Static, Unchecked, Enforced, Unenforced, Stable, Unstable, AlwaysValid, MaybeInvalid};
+use super::{Variant, Invariant, ReadOnly, Aliased, Exclusive, Static, Unchecked, Enforced, Unenforced, Stable, Unstable, AlwaysValid, MaybeInvalid};
 use crate::stability::*;
 mod consume;
 pub use consume::Consume;
@@ -15,6 +18,9 @@ pub use consume::Consume;
 mod flatten;
 pub use flatten::Flatten;
 
+mod opaque_to;
+pub use opaque_to::DefinedLayout;
+
 /// A marker trait implemented if the layout `T` is compatible with the layout
 /// `Self`.
 pub unsafe trait FromLayout<
@@ -44,14 +50,22 @@ where
 {}
 
 #[rustfmt::skip] unsafe impl<
-  UVis, U, URest,
+  UVis, U, URest, UTarget,
   Options,
 > FromLayout<PNil, Options>
-         for PCons<Array<UVis, U, UTerm>, URest>
+         for PCons<Array<UVis, U, UTerm, UTarget>, URest>
 where
     URest: FromLayout<PNil, Options>,
 {}
 
+#[rustfmt::skip] unsafe impl<
+  UVis, U, URest,
+  Options,
+> FromLayout<PNil, Options>
+         for PCons<Opaque<UVis, U>, URest>
+where
+    U: DefinedLayout,
+{}
 
 /// ```rust
 /// use typic::private::transmute::{Stable, Variant, Static, Enforced, AlwaysValid, from_layout::FromLayout};
@@ -73,11 +87,11 @@ mod bytes_to {
     use super::*;
 
     /// [Bytes|_] -> [Array|_]
-    #[rustfmt::skip] unsafe impl<TVis, TKind, TSize, TRest, UVis, U, USize, URest,
+    #[rustfmt::skip] unsafe impl<TVis, TKind, TSize, TRest, UVis, U, USize, URest, UTarget,
         Options,
     >
     FromLayout<PCons<Bytes<TVis, TKind, TSize>, TRest>, Options>
-           for PCons<Array<UVis, U, USize>, URest>
+           for PCons<Array<UVis, U, USize, UTarget>, URest>
     where
         Self: Flatten,
         <Self as Flatten>::Output:
@@ -90,16 +104,130 @@ mod bytes_to {
     FromLayout<PCons<Bytes<TVis, TKind, TSize>, TRest>, (Variance, Alignment, Transparency, Stability, Validity)>
            for PCons<Bytes<UVis, UKind, USize>, URest>
     where
-        Bytes<UVis, UKind, USize>: BytesFromBytes<Bytes<TVis, TKind, TSize>, Variance, Transparency, Validity>,
+        TKind: kind::Freeze,
+        UKind: kind::Freeze,
+        <UKind as kind::Freeze>::Interior: InteriorFromInterior<<TKind as kind::Freeze>::Interior, TSize, USize, Variance>,
+        Variance: Bytewise<<TKind as kind::Freeze>::Interior, <UKind as kind::Freeze>::Interior>,
+        Bytes<UVis, kind::Frozen<UKind>, USize>:
+          BytesFromBytes<Bytes<TVis, kind::Frozen<TKind>, TSize>, BytewiseVariance<Variance, <TKind as kind::Freeze>::Interior, <UKind as kind::Freeze>::Interior>, Transparency, Validity>,
         USize: Consume<TSize>,
+        TSize: num::Min<USize>,
+        TKind: kind::Split<num::Minimum<TSize, USize>>,
 
-        Bytes<TVis, TKind, <USize as Consume<TSize>>::TSize>: blv::Add<TRest>,
+        Bytes<TVis, kind::Remainder<TKind, num::Minimum<TSize, USize>>, <USize as Consume<TSize>>::TSize>: blv::Add<TRest>,
         Bytes<UVis, UKind, <USize as Consume<TSize>>::USize>: blv::Add<URest>,
 
         blv::Sum<Bytes<UVis, UKind, <USize as Consume<TSize>>::USize>, URest>:
-          FromLayout<blv::Sum<Bytes<TVis, TKind, <USize as Consume<TSize>>::TSize>, TRest>, (Variance, Alignment, Transparency, Stability, Validity)>
+          FromLayout<blv::Sum<Bytes<TVis, kind::Remainder<TKind, num::Minimum<TSize, USize>>, <USize as Consume<TSize>>::TSize>, TRest>, (Variance, Alignment, Transparency, Stability, Validity)>
+    {}
+
+    /// [Bytes|_] -> [Coproduct|_], where the leading bytes are zero-sized.
+    #[rustfmt::skip] unsafe impl<TVis, TKind, TRest, UH, UT, URest, Options>
+    FromLayout<PCons<Bytes<TVis, TKind, num::UTerm>, TRest>, Options>
+           for PCons<CCons<UH, UT>, URest>
+    where
+        Self: FromLayout<TRest, Options>,
+    {}
+
+    /// [Bytes|_] -> [Coproduct|_], if validity is not enforced. The bytes
+    /// must be transmutable into the erasure of the coproduct's variants.
+    #[rustfmt::skip] unsafe impl<TVis, TKind, A, B, TRest, UH, UT, URest,
+      Variance, Alignment, Transparency, Stability>
+    FromLayout<PCons<Bytes<TVis, TKind, num::UInt<A, B>>, TRest>, (Variance, Alignment, Transparency, Stability, MaybeInvalid)>
+           for PCons<CCons<UH, UT>, URest>
+    where
+        Self: blv::ops::Lower,
+        blv::ops::Lowered<Self>:
+          FromLayout<PCons<Bytes<TVis, TKind, num::UInt<A, B>>, TRest>, (Variance, Alignment, Transparency, Stability, MaybeInvalid)>,
+    {}
+
+    /// [Bytes|_] -> [Opaque|_], where the leading bytes are zero-sized.
+    #[rustfmt::skip] unsafe impl<TVis, TKind, TRest, UVis, U, URest, Options>
+    FromLayout<PCons<Bytes<TVis, TKind, num::UTerm>, TRest>, Options>
+           for PCons<Opaque<UVis, U>, URest>
+    where
+        Self: FromLayout<TRest, Options>,
+    {}
+
+    /// [Bytes|_] -> [Opaque|_]
+    #[rustfmt::skip] unsafe impl<TVis, TKind, A, B, TRest, UVis, U, URest, Options>
+    FromLayout<PCons<Bytes<TVis, TKind, num::UInt<A, B>>, TRest>, Options>
+           for PCons<Opaque<UVis, U>, URest>
+    where
+        U: DefinedLayout,
     {}
 
+    /// Implemented if bytes whose interior mutability is `T` (`B1` if they
+    /// are interior mutable, and `B0` otherwise) may become bytes whose
+    /// interior mutability is `Self`.
+    ///
+    /// Unless the bytes are shared, interior mutability may be gained or
+    /// lost: a value (or the referent of a unique reference) may be mutated
+    /// regardless. Shared bytes must keep it, lest bytes that one reference
+    /// observes as immutable be mutated through another.
+    pub trait InteriorFromInterior<T, TSize, USize, Variance> {}
+
+    impl<T, U, TSize, USize> InteriorFromInterior<T, TSize, USize, Variant> for U {}
+
+    impl<T, U, TSize, USize> InteriorFromInterior<T, TSize, USize, Invariant> for U {}
+
+    impl<T, U, TSize, USize> InteriorFromInterior<T, TSize, USize, Exclusive> for U {}
+
+    impl<I, TSize, USize, V> InteriorFromInterior<I, TSize, USize, Aliased<V>> for I {}
+
+    // Empty bytes are vacuously transmutable (e.g., the padding before a
+    // field of a cell).
+    impl<USize, V> InteriorFromInterior<num::B0, num::UTerm, USize, Aliased<V>> for num::B1 {}
+
+    impl<USize, V> InteriorFromInterior<num::B1, num::UTerm, USize, Aliased<V>> for num::B0 {}
+
+    impl<A, B, V> InteriorFromInterior<num::B0, num::UInt<A, B>, num::UTerm, Aliased<V>> for num::B1 {}
+
+    impl<A, B, V> InteriorFromInterior<num::B1, num::UInt<A, B>, num::UTerm, Aliased<V>> for num::B0 {}
+
+    /// The variance of bytes of variance `Self`, whose interior mutability
+    /// is `TInterior` in the source and `UInterior` in the destination.
+    pub trait Bytewise<TInterior, UInterior> {
+        type Variance;
+    }
+
+    pub type BytewiseVariance<Variance, TInterior, UInterior> = <Variance as Bytewise<TInterior, UInterior>>::Variance;
+
+    impl<TInterior, UInterior> Bytewise<TInterior, UInterior> for Variant {
+        type Variance = Variant;
+    }
+
+    impl<TInterior, UInterior> Bytewise<TInterior, UInterior> for Invariant {
+        type Variance = Invariant;
+    }
+
+    /// Shared bytes that are not interior mutable can only be read, so their
+    /// validity may expand...
+    impl<UInterior> Bytewise<num::B0, UInterior> for Aliased<Variant> {
+        type Variance = ReadOnly;
+    }
+
+    impl<UInterior> Bytewise<num::B0, UInterior> for Aliased<Invariant> {
+        type Variance = Invariant;
+    }
+
+    /// ...but shared bytes that are may be written, too.
+    impl<V, UInterior> Bytewise<num::B1, UInterior> for Aliased<V> {
+        type Variance = Invariant;
+    }
+
+    /// Likewise, the bytes of a unique referent that became a shared referent
+    /// may only be read, unless they became interior mutable; then, they may
+    /// be written, and are read as the source type once the shared referent
+    /// is dropped.
+    impl<TInterior> Bytewise<TInterior, num::B0> for Exclusive {
+        type Variance = ReadOnly;
+    }
+
+    impl<TInterior> Bytewise<TInterior, num::B1> for Exclusive {
+        type Variance = Invariant;
+    }
+
     /// Implemented if a byte of `TKind` is transmutable to a byte of `Self`.
     pub trait BytesFromBytes<T, Variance, Transparency, Validity> {}
 
@@ -128,41 +256,199 @@ mod bytes_to {
     }
 
     constrain![
-      kind::NonZero       => kind::NonZero       ,
       kind::Initialized   => kind::Initialized   ,
       kind::Uninitialized => kind::Uninitialized ,
     ];
 
     macro_rules! relax {
-      ($($TKind: path => $UKind: path,)*) => {
+      ($($({$($G: ident),*})? $TKind: ty => $UKind: ty,)*) => {
         $(
           /// Regardless of variance and transparency, this `pub` to `pub` conversion is safe.
-          impl<TSize, USize, Transparency, Validity>
+          impl<$($($G,)*)? TSize, USize, Transparency, Validity>
           BytesFromBytes<Bytes<Pub,  $TKind, TSize>, Variant, Transparency, Validity>
                      for Bytes<Pub,  $UKind, USize>
           {}
 
           /// A `priv` to `pub` conversion is safe only if the transmutation is variant.
-          impl<TSize, USize, Transparency, Validity>
+          impl<$($($G,)*)? TSize, USize, Transparency, Validity>
           BytesFromBytes<Bytes<Priv, $TKind, TSize>, Variant, Transparency, Validity>
                      for Bytes<Pub,  $UKind, USize>
           {}
 
           /// A `priv`/`pub` to `priv` conversion is only safe if transparency is unchecked.
-          impl<TSize, USize, TVis, Validity>
+          impl<$($($G,)*)? TSize, USize, TVis, Validity>
           BytesFromBytes<Bytes<TVis, $TKind, TSize>, Variant, Unenforced, Validity>
                      for Bytes<Priv, $UKind, USize>
           {}
+
+          /// Bytes that may only be read may expand, too...
+          impl<$($($G,)*)? TSize, USize, Transparency, Validity>
+          BytesFromBytes<Bytes<Pub,  $TKind, TSize>, ReadOnly, Transparency, Validity>
+                     for Bytes<Pub,  $UKind, USize>
+          {}
+
+          /// ...but private bytes may not become public.
+          impl<$($($G,)*)? TSize, USize, TVis, Validity>
+          BytesFromBytes<Bytes<TVis, $TKind, TSize>, ReadOnly, Unenforced, Validity>
+                     for Bytes<Priv, $UKind, USize>
+          {}
         )*
       };
     }
 
     relax![
-      kind::NonZero       => kind::Uninitialized ,
-      kind::NonZero       => kind::Initialized   ,
-      kind::Initialized   => kind::Uninitialized ,
+      kind::Initialized         => kind::Uninitialized ,
+      {S, E} kind::Range<S, E>  => kind::Uninitialized ,
+      {S, E} kind::Range<S, E>  => kind::Initialized   ,
+      kind::Char                => kind::Uninitialized ,
+      kind::Char                => kind::Initialized   ,
     ];
 
+    macro_rules! narrow {
+      ($($({$($G: ident),*})? $TKind: ty => $UKind: ty,)*) => {
+        $(
+          /// Regardless of variance and transparency, this `pub` to `pub` conversion is safe.
+          impl<$($($G,)*)? TSize, USize, Transparency>
+          BytesFromBytes<Bytes<Pub,  $TKind, TSize>, Variant, Transparency, MaybeInvalid>
+                     for Bytes<Pub,  $UKind, USize>
+          {}
+
+          /// A `priv` to `pub` conversion is safe only if the transmutation is variant.
+          impl<$($($G,)*)? TSize, USize, Transparency>
+          BytesFromBytes<Bytes<Priv, $TKind, TSize>, Variant, Transparency, MaybeInvalid>
+                     for Bytes<Pub,  $UKind, USize>
+          {}
+
+          /// A `priv`/`pub` to `priv` conversion is only safe if transparency is unchecked.
+          impl<$($($G,)*)? TSize, USize, TVis>
+          BytesFromBytes<Bytes<TVis, $TKind, TSize>, Variant, Unenforced, MaybeInvalid>
+                     for Bytes<Priv, $UKind, USize>
+          {}
+        )*
+      };
+    }
+
+    // If validity is not enforced, bytes may become bytes of a narrower kind;
+    // whether they are valid must be checked by other means.
+    narrow![
+      {S, E} kind::Initialized  => kind::Range<S, E>   ,
+      {S, E} kind::Char         => kind::Range<S, E>   ,
+      kind::Initialized         => kind::Char          ,
+      {S, E} kind::Range<S, E>  => kind::Char          ,
+    ];
+
+    /// Implemented if a range of `TSize` bytes, `T`, is transmutable into
+    /// a range of `USize` bytes, `Self`.
+    pub trait RangeFromRange<T, TSize, USize, Variance, Validity> {}
+
+    /// A range may only become a range over the same number of bytes. If the
+    /// transmutation is variant, its values must be a subset of the
+    /// destination's; otherwise, the ranges must be equal. If validity is not
+    /// enforced, any range may become any other.
+    impl<TStart, TEnd, UStart, UEnd, TSize, USize, Variance, Validity>
+    RangeFromRange<kind::Range<TStart, TEnd>, TSize, USize, Variance, Validity>
+               for kind::Range<UStart, UEnd>
+    where
+        (TStart, TEnd, TSize): Within<(UStart, UEnd, USize), Variance>,
+        Validity: Admits<<(TStart, TEnd, TSize) as Within<(UStart, UEnd, USize), Variance>>::Output>,
+    {}
+
+    /// `Output` is `B1` if every value of the range `(Start, End, Size)` is
+    /// a value of the range `U`, and `B0` otherwise.
+    pub trait Within<U, Variance> {
+        type Output;
+    }
+
+    impl<TStart, TEnd, TSize, UStart, UEnd, USize>
+    Within<(UStart, UEnd, USize), Variant> for (TStart, TEnd, TSize)
+    where
+        UStart: num::IsLessOrEqual<TStart>,
+        TEnd: num::IsLessOrEqual<UEnd>,
+        TSize: num::IsEqual<USize>,
+        num::LeEq<UStart, TStart>: num::BitAnd<num::LeEq<TEnd, UEnd>>,
+        num::And<num::LeEq<UStart, TStart>, num::LeEq<TEnd, UEnd>>: num::BitAnd<num::Eq<TSize, USize>>,
+    {
+        type Output = num::And<num::And<num::LeEq<UStart, TStart>, num::LeEq<TEnd, UEnd>>, num::Eq<TSize, USize>>;
+    }
+
+    impl<TStart, TEnd, TSize, UStart, UEnd, USize>
+    Within<(UStart, UEnd, USize), ReadOnly> for (TStart, TEnd, TSize)
+    where
+        UStart: num::IsLessOrEqual<TStart>,
+        TEnd: num::IsLessOrEqual<UEnd>,
+        TSize: num::IsEqual<USize>,
+        num::LeEq<UStart, TStart>: num::BitAnd<num::LeEq<TEnd, UEnd>>,
+        num::And<num::LeEq<UStart, TStart>, num::LeEq<TEnd, UEnd>>: num::BitAnd<num::Eq<TSize, USize>>,
+    {
+        type Output = num::And<num::And<num::LeEq<UStart, TStart>, num::LeEq<TEnd, UEnd>>, num::Eq<TSize, USize>>;
+    }
+
+    impl<TStart, TEnd, TSize, UStart, UEnd, USize>
+    Within<(UStart, UEnd, USize), Invariant> for (TStart, TEnd, TSize)
+    where
+        UStart: num::IsEqual<TStart>,
+        TEnd: num::IsEqual<UEnd>,
+        TSize: num::IsEqual<USize>,
+        num::Eq<UStart, TStart>: num::BitAnd<num::Eq<TEnd, UEnd>>,
+        num::And<num::Eq<UStart, TStart>, num::Eq<TEnd, UEnd>>: num::BitAnd<num::Eq<TSize, USize>>,
+    {
+        type Output = num::And<num::And<num::Eq<UStart, TStart>, num::Eq<TEnd, UEnd>>, num::Eq<TSize, USize>>;
+    }
+
+    /// Implemented if a transmutation whose validity is `Within` is admitted
+    /// under the validity option `Self`.
+    pub trait Admits<Within> {}
+
+    impl<Validity> Admits<num::B1> for Validity {}
+
+    impl Admits<num::B0> for MaybeInvalid {}
+
+    /// Regardless of variance and transparency, this `pub` to `pub` conversion is safe.
+    impl<TStart, TEnd, UStart, UEnd, TSize, USize, Variance, Transparency, Validity>
+    BytesFromBytes<Bytes<Pub,  kind::Range<TStart, TEnd>, TSize>, Variance, Transparency, Validity>
+               for Bytes<Pub,  kind::Range<UStart, UEnd>, USize>
+    where
+        kind::Range<UStart, UEnd>: RangeFromRange<kind::Range<TStart, TEnd>, TSize, USize, Variance, Validity>,
+    {}
+
+    /// A `priv` to `pub` conversion is safe only if the transmutation is variant.
+    impl<TStart, TEnd, UStart, UEnd, TSize, USize, Transparency, Validity>
+    BytesFromBytes<Bytes<Priv, kind::Range<TStart, TEnd>, TSize>, Variant, Transparency, Validity>
+               for Bytes<Pub,  kind::Range<UStart, UEnd>, USize>
+    where
+        kind::Range<UStart, UEnd>: RangeFromRange<kind::Range<TStart, TEnd>, TSize, USize, Variant, Validity>,
+    {}
+
+    /// A `priv`/`pub` to `priv` conversion is only safe if transparency is unchecked.
+    impl<TStart, TEnd, UStart, UEnd, TSize, USize, TVis, Variance, Validity>
+    BytesFromBytes<Bytes<TVis, kind::Range<TStart, TEnd>, TSize>, Variance, Unenforced, Validity>
+               for Bytes<Priv, kind::Range<UStart, UEnd>, USize>
+    where
+        kind::Range<UStart, UEnd>: RangeFromRange<kind::Range<TStart, TEnd>, TSize, USize, Variance, Validity>,
+    {}
+
+    // A `char` may only be transmuted, whole, into a `char`. Unlike the other
+    // kinds, the sizes of the source and destination bytes must therefore
+    // match exactly.
+
+    /// Regardless of variance and transparency, this `pub` to `pub` conversion is safe.
+    impl<Size, Variance, Transparency, Validity>
+    BytesFromBytes<Bytes<Pub,  kind::Char, Size>, Variance, Transparency, Validity>
+               for Bytes<Pub,  kind::Char, Size>
+    {}
+
+    /// A `priv` to `pub` conversion is safe only if the transmutation is variant.
+    impl<Size, Transparency, Validity>
+    BytesFromBytes<Bytes<Priv, kind::Char, Size>, Variant, Transparency, Validity>
+               for Bytes<Pub,  kind::Char, Size>
+    {}
+
+    /// A `priv`/`pub` to `priv` conversion is only safe if transparency is unchecked.
+    impl<Size, TVis, Variance, Validity>
+    BytesFromBytes<Bytes<TVis, kind::Char, Size>, Variance, Unenforced, Validity>
+               for Bytes<Priv, kind::Char, Size>
+    {}
+
     // If either sizes are empty, `BytesFromBytes` vacuously holds.
     // this is sketchy, but I think it's alright because of how
     // BytesFromBytes is used alongside `Consume`. Unfortunately,
@@ -172,11 +458,30 @@ mod bytes_to {
       BytesFromBytes<Bytes<Pub, kind::Uninitialized, num::UTerm>, Variance, Transparency, Validity>
     for              Bytes<Pub, kind::Initialized, num::UInt<A, B>> {}
 
+    impl<S, E, A, B, Variance, Transparency, Validity>
+      BytesFromBytes<Bytes<Pub, kind::Uninitialized, num::UTerm>, Variance, Transparency, Validity>
+    for              Bytes<Pub, kind::Range<S, E>, num::UInt<A, B>> {}
+
+    // Likewise, empty padding in the destination vacuously holds, even if
+    // the transmutation is invariant (e.g., the padding before the first
+    // field of a referent).
+    impl<A, B, Transparency, Validity>
+      BytesFromBytes<Bytes<Pub, kind::Initialized, num::UInt<A, B>>, Invariant, Transparency, Validity>
+    for              Bytes<Pub, kind::Uninitialized, num::UTerm> {}
+
+    impl<S, E, A, B, Transparency, Validity>
+      BytesFromBytes<Bytes<Pub, kind::Range<S, E>, num::UInt<A, B>>, Invariant, Transparency, Validity>
+    for              Bytes<Pub, kind::Uninitialized, num::UTerm> {}
+
+    impl<A, B, Transparency, Validity>
+      BytesFromBytes<Bytes<Pub, kind::Char, num::UInt<A, B>>, Invariant, Transparency, Validity>
+    for              Bytes<Pub, kind::Uninitialized, num::UTerm> {}
+
     // todo: wtf. why did I write this?
     // /// [Bytes|_] -> [Reference|_]
-    // #[rustfmt::skip] unsafe impl<'u, TVis, TKind, TRest, UVis, UK, U, URest, Options>
+    // #[rustfmt::skip] unsafe impl<'u, TVis, TKind, TRest, UVis, UK, U, URest, Options, UTarget>
     // FromLayout<PCons<Bytes<TVis, TKind, num::UTerm>, TRest>, Options>
-    //      for PCons<Reference<'u, UVis, UK, U>, URest>
+    //      for PCons<Reference<'u, UVis, UK, U, UTarget>, URest>
     // where
     //     Self: FromLayout<TRest, Options>,
     // {}
@@ -186,63 +491,201 @@ mod array_to {
     use super::*;
 
     /// [Array|_] -> [Array|_]
-    #[rustfmt::skip] unsafe impl<TVis, T, TSize, TRest, UVis, U, USize, URest, Options>
-    FromLayout<PCons<Array<TVis, T, TSize>, TRest>, Options>
-         for PCons<Array<UVis, U, USize>, URest>
+    #[rustfmt::skip] unsafe impl<TVis, T, TSize, TRest, TTarget, UVis, U, USize, URest, UTarget, Options>
+    FromLayout<PCons<Array<TVis, T, TSize, TTarget>, TRest>, Options>
+         for PCons<Array<UVis, U, USize, UTarget>, URest>
     where
-        PCons<Array<TVis, T, TSize>, TRest>: Flatten,
-        PCons<Array<UVis, U, USize>, URest>: Flatten,
+        PCons<Array<TVis, T, TSize, TTarget>, TRest>: Flatten,
+        PCons<Array<UVis, U, USize, UTarget>, URest>: Flatten,
 
-        <PCons<Array<UVis, U, USize>, URest> as Flatten>::Output:
-            FromLayout<<PCons<Array<TVis, T, TSize>, TRest> as Flatten>::Output, Options>,
+        <PCons<Array<UVis, U, USize, UTarget>, URest> as Flatten>::Output:
+            FromLayout<<PCons<Array<TVis, T, TSize, TTarget>, TRest> as Flatten>::Output, Options>,
     {}
 
     /// [Array|_] -> [Bytes|_]
-    #[rustfmt::skip] unsafe impl<TVis, T, TSize, TRest, UVis, UKind, USize, URest, Options>
-    FromLayout<PCons<Array<TVis, T, TSize>, TRest>, Options>
+    #[rustfmt::skip] unsafe impl<TVis, T, TSize, TRest, TTarget, UVis, UKind, USize, URest, Options>
+    FromLayout<PCons<Array<TVis, T, TSize, TTarget>, TRest>, Options>
          for PCons<Bytes<UVis, UKind, USize>, URest>
     where
-        PCons<Array<TVis, T, TSize>, TRest>: Flatten,
+        PCons<Array<TVis, T, TSize, TTarget>, TRest>: Flatten,
 
-        Self: FromLayout<<PCons<Array<TVis, T, TSize>, TRest> as Flatten>::Output, Options>,
+        Self: FromLayout<<PCons<Array<TVis, T, TSize, TTarget>, TRest> as Flatten>::Output, Options>,
+    {}
+
+    /// [Array|_] -> [Coproduct|_]
+    #[rustfmt::skip] unsafe impl<TVis, T, TSize, TRest, TTarget, UH, UT, URest, Options>
+    FromLayout<PCons<Array<TVis, T, TSize, TTarget>, TRest>, Options>
+         for PCons<CCons<UH, UT>, URest>
+    where
+        PCons<Array<TVis, T, TSize, TTarget>, TRest>: Flatten,
+
+        Self: FromLayout<<PCons<Array<TVis, T, TSize, TTarget>, TRest> as Flatten>::Output, Options>,
+    {}
+
+    /// [Array|_] -> [Opaque|_]
+    #[rustfmt::skip] unsafe impl<TVis, T, TSize, TRest, TTarget, UVis, U, URest, Options>
+    FromLayout<PCons<Array<TVis, T, TSize, TTarget>, TRest>, Options>
+         for PCons<Opaque<UVis, U>, URest>
+    where
+        PCons<Array<TVis, T, TSize, TTarget>, TRest>: Flatten,
+
+        Self: FromLayout<<PCons<Array<TVis, T, TSize, TTarget>, TRest> as Flatten>::Output, Options>,
     {}
 
     /// [Array|_] -> [Reference|_]
-    #[rustfmt::skip] unsafe impl<'u, TVis, T, TSize, TRest, UK, UVis, U, URest, Options>
-    FromLayout<PCons<Array<TVis, T, TSize>, TRest>, Options>
-         for PCons<Reference<'u, UVis, UK, U>, URest>
+    #[rustfmt::skip] unsafe impl<'u, TVis, T, TSize, TRest, TTarget, UK, UVis, U, URest, UTarget, Options>
+    FromLayout<PCons<Array<TVis, T, TSize, TTarget>, TRest>, Options>
+         for PCons<Reference<'u, UVis, UK, U, UTarget>, URest>
     where
-        PCons<Array<TVis, T, TSize>, TRest>: Flatten,
+        PCons<Array<TVis, T, TSize, TTarget>, TRest>: Flatten,
 
-        Self: FromLayout<<PCons<Array<TVis, T, TSize>, TRest> as Flatten>::Output, Options>,
+        Self: FromLayout<<PCons<Array<TVis, T, TSize, TTarget>, TRest> as Flatten>::Output, Options>,
     {}
 }
 
 mod reference_to {
     use super::*;
+    use super::bytes_to::InteriorFromInterior;
 
     /// [Reference|_] -> [Array|_]
-    #[rustfmt::skip] unsafe impl<'t, TVis, T, TK, TRest, UVis, U, USize, URest, Options>
-    FromLayout<PCons<Reference<'t, TVis, TK, T>, TRest>, Options>
-         for PCons<Array<UVis, U, USize>, URest>
+    #[rustfmt::skip] unsafe impl<'t, TVis, T, TK, TRest, TTarget, UVis, U, USize, URest, UTarget, Options>
+    FromLayout<PCons<Reference<'t, TVis, TK, T, TTarget>, TRest>, Options>
+         for PCons<Array<UVis, U, USize, UTarget>, URest>
     where
         Self: Flatten,
         <Self as Flatten>::Output:
-          FromLayout<PCons<Reference<'t, TVis, TK, T>, TRest>, Options>,
+          FromLayout<PCons<Reference<'t, TVis, TK, T, TTarget>, TRest>, Options>,
     {}
 
     /// [Reference|_] -> [Bytes|_]
-    #[rustfmt::skip] unsafe impl<'t, TVis, T, TK, TRest, UVis, UKind, USize, URest, Options>
-    FromLayout<PCons<Reference<'t, TVis, TK, T>, TRest>, Options>
+    #[rustfmt::skip] unsafe impl<'t, TVis, T, TK, TRest, TTarget, UVis, UKind, USize, URest, Options>
+    FromLayout<PCons<Reference<'t, TVis, TK, T, TTarget>, TRest>, Options>
          for PCons<Bytes<UVis, UKind, USize>, URest>
     where
-        Self: FromLayout<ReferenceBytes<TVis, TRest>, Options>,
+        TK: kind::Freeze,
+        <TK as kind::Freeze>::Interior: kind::Thaw<kind::NonZero<target::PointerWidth<TTarget>>>,
+        TTarget: target::Target,
+        Self: FromLayout<ReferenceBytes<TVis, TK, TRest, TTarget>, Options>,
+    {}
+
+    /// [Reference|_] -> [Opaque|_]
+    #[rustfmt::skip] unsafe impl<'t, TVis, T, TK, TRest, TTarget, UVis, U, URest, Options>
+    FromLayout<PCons<Reference<'t, TVis, TK, T, TTarget>, TRest>, Options>
+         for PCons<Opaque<UVis, U>, URest>
+    where
+        U: DefinedLayout,
+    {}
+
+    /// Implemented if a reference of kind `TK` to `T` may become a reference
+    /// of kind `Self` to `U`, on `Target`.
+    pub trait FromMutability<TK, T, U, Target> {}
+    impl<T, U, Target> FromMutability<Unique, T, U, Target> for Unique {}
+    impl<T, U, Target> FromMutability<Unique, T, U, Target> for Shared {}
+    impl<T, U, Target> FromMutability<Shared, T, U, Target> for Shared {}
+
+    /// A `Box` may be leaked into a borrow...
+    impl<T, U, Target> FromMutability<Owned, T, U, Target> for Unique {}
+    impl<T, U, Target> FromMutability<Owned, T, U, Target> for Shared {}
+
+    /// ...or become another `Box`, which will free its referent with the
+    /// referent's own size and alignment.
+    impl<T, U, Target> FromMutability<Owned, T, U, Target> for Owned
+    where
+        T: Layout<Public, Target>,
+        U: Layout<Public, Target,
+            Size = <T as Layout<Public, Target>>::Size,
+            Align = <T as Layout<Public, Target>>::Align,
+        >,
     {}
 
-    pub trait FromMutability<T> {}
-    impl FromMutability<Unique> for Unique {}
-    impl FromMutability<Unique> for Shared {}
-    impl FromMutability<Shared> for Shared {}
+    /// The variance of a reference within bytes of variance `Self`, whose
+    /// interior mutability is `Interior`.
+    ///
+    /// A reference in shared bytes that are interior mutable may be
+    /// overwritten through a cell, so its referent must keep its validity;
+    /// e.g., the referent of a `&Cell<&T>`.
+    pub trait Rewritable<Interior> {
+        type Variance;
+    }
+
+    pub type RewritableVariance<Variance, Interior> = <Variance as Rewritable<Interior>>::Variance;
+
+    impl<Interior> Rewritable<Interior> for Variant {
+        type Variance = Variant;
+    }
+
+    impl<Interior> Rewritable<Interior> for Invariant {
+        type Variance = Invariant;
+    }
+
+    impl<V> Rewritable<num::B0> for Aliased<V> {
+        type Variance = Aliased<V>;
+    }
+
+    impl<V> Rewritable<num::B1> for Aliased<V> {
+        type Variance = Aliased<Invariant>;
+    }
+
+    impl Rewritable<num::B0> for Exclusive {
+        type Variance = Exclusive;
+    }
+
+    impl Rewritable<num::B1> for Exclusive {
+        type Variance = Aliased<Invariant>;
+    }
+
+    /// The variance of the referent of a reference of kind `TK` that
+    /// becomes a reference of kind `UK`, within bytes of variance `Self`.
+    ///
+    /// The referent of a shared reference is shared, as is every referent
+    /// reached through it; e.g., the referent of a `&&mut T` may be mutated
+    /// only through cells. A shared referent may only be read, so its
+    /// validity may expand; unless the reference itself may be overwritten
+    /// (e.g., the referent of a `&mut &T`).
+    pub trait Referent<TK, UK> {
+        type Variance;
+    }
+
+    pub type ReferentVariance<Variance, TK, UK> = <Variance as Referent<TK, UK>>::Variance;
+
+    impl<TK, UK, V> Referent<TK, UK> for Aliased<V> {
+        type Variance = Aliased<V>;
+    }
+
+    macro_rules! referent {
+      ($($Variance: ty, $TK: ty, $UK: ty => $Output: ty;)*) => {
+        $(
+          impl Referent<$TK, $UK> for $Variance {
+            type Variance = $Output;
+          }
+        )*
+      };
+    }
+
+    // A unique reference that becomes a shared reference may no longer be
+    // mutated through, except through cells, and its referent is not shared
+    // with any other reference, for as long as the shared reference lives.
+    // Nor may any unique reference reached through it be mutated through.
+    referent! {
+      Variant,   Shared, Shared => Aliased<Variant>;
+      Variant,   Unique, Shared => Exclusive;
+      Variant,   Unique, Unique => Invariant;
+      Variant,   Owned,  Shared => Exclusive;
+      Variant,   Owned,  Unique => Invariant;
+      Variant,   Owned,  Owned  => Invariant;
+      Invariant, Shared, Shared => Aliased<Invariant>;
+      Invariant, Unique, Shared => Aliased<Invariant>;
+      Invariant, Unique, Unique => Invariant;
+      Invariant, Owned,  Shared => Aliased<Invariant>;
+      Invariant, Owned,  Unique => Invariant;
+      Invariant, Owned,  Owned  => Invariant;
+      Exclusive, Shared, Shared => Aliased<Variant>;
+      Exclusive, Unique, Shared => Exclusive;
+      Exclusive, Unique, Unique => Exclusive;
+      Exclusive, Owned,  Shared => Exclusive;
+      Exclusive, Owned,  Unique => Exclusive;
+      Exclusive, Owned,  Owned  => Exclusive;
+    }
 
     pub trait FromAlignment<T, Stability> {}
 
@@ -255,13 +698,21 @@ mod reference_to {
     impl<T, U> FromAlignment<T, Unstable> for U {}
 
     /// [Reference|_] -> [Reference|_]
-    #[rustfmt::skip] unsafe impl<'t, 'u, TVis, T, TK, TRest, UVis, U, UK, URest, Variance, Transparency, Stability, Validity>
-    FromLayout<PCons<Reference<'t, TVis, TK, T>, TRest>, (Variance, Unchecked, Transparency, Stability, Validity)>
-           for PCons<Reference<'u, UVis, UK, U>, URest>
+    ///
+    /// The validity of referents is always enforced; it cannot be checked
+    /// when the reference is transmuted.
+    #[rustfmt::skip] unsafe impl<'t, 'u, TVis, T, TK, TRest, UVis, U, UK, URest, Target, Variance, Transparency, Stability, Validity>
+    FromLayout<PCons<Reference<'t, TVis, TK, T, Target>, TRest>, (Variance, Unchecked, Transparency, Stability, Validity)>
+           for PCons<Reference<'u, UVis, UK, U, Target>, URest>
     where
         't: 'u,
-        UK: FromMutability<TK>,
-        U: FromType<T, Invariant, Unchecked, Transparency, Stability, Validity>,
+        TK: kind::Freeze,
+        UK: kind::Freeze,
+        <UK as kind::Freeze>::Interior: InteriorFromInterior<<TK as kind::Freeze>::Interior, num::U1, num::U1, Variance>,
+        kind::Frozen<UK>: FromMutability<kind::Frozen<TK>, T, U, Target>,
+        Variance: Rewritable<<UK as kind::Freeze>::Interior>,
+        RewritableVariance<Variance, <UK as kind::Freeze>::Interior>: Referent<kind::Frozen<TK>, kind::Frozen<UK>>,
+        U: FromType<T, ReferentVariance<RewritableVariance<Variance, <UK as kind::Freeze>::Interior>, kind::Frozen<TK>, kind::Frozen<UK>>, Unchecked, Transparency, Stability, AlwaysValid, Target>,
     {}
 
     /// `[Reference|_] -> [Reference|_]`
@@ -273,16 +724,105 @@ mod reference_to {
     /// type T = SharedRef<'static, Pub, ()>;
     /// can_transmute::<PCons<T, PNil>, PCons<T, PNil>>();
     /// ```
-    #[rustfmt::skip] unsafe impl<'t, 'u, TVis, T, TK, TRest, UVis, U, UK, URest, Variance, Transparency, Stability, Validity>
-    FromLayout<PCons<Reference<'t, TVis, TK, T>, TRest>, (Variance, Static, Transparency, Stability, Validity)>
-           for PCons<Reference<'u, UVis, UK, U>, URest>
+    #[rustfmt::skip] unsafe impl<'t, 'u, TVis, T, TK, TRest, UVis, U, UK, URest, Target, Variance, Transparency, Stability, Validity>
+    FromLayout<PCons<Reference<'t, TVis, TK, T, Target>, TRest>, (Variance, Static, Transparency, Stability, Validity)>
+           for PCons<Reference<'u, UVis, UK, U, Target>, URest>
     where
         't: 'u,
-        UK: FromMutability<TK>,
-        U: FromAlignment<T, Stability> + AlignedTo<T> + FromType<T, Invariant, Static, Transparency, Stability, Validity>,
+        TK: kind::Freeze,
+        UK: kind::Freeze,
+        <UK as kind::Freeze>::Interior: InteriorFromInterior<<TK as kind::Freeze>::Interior, num::U1, num::U1, Variance>,
+        kind::Frozen<UK>: FromMutability<kind::Frozen<TK>, T, U, Target>,
+        Variance: Rewritable<<UK as kind::Freeze>::Interior>,
+        RewritableVariance<Variance, <UK as kind::Freeze>::Interior>: Referent<kind::Frozen<TK>, kind::Frozen<UK>>,
+        U: FromAlignment<T, Stability> + AlignedTo<T, Target>
+          + FromType<T, ReferentVariance<RewritableVariance<Variance, <UK as kind::Freeze>::Interior>, kind::Frozen<TK>, kind::Frozen<UK>>, Static, Transparency, Stability, AlwaysValid, Target>,
     {}
 }
 
+mod coproduct_to {
+    use super::*;
+
+    /// Implemented if the layout `Self` is transmutable from each variant of
+    /// the coproduct `Variants`, followed by `TRest`.
+    pub unsafe trait FromVariants<Variants, TRest, Options> {}
+
+    unsafe impl<U, TRest, Options> FromVariants<CNil, TRest, Options> for U {}
+
+    #[rustfmt::skip] unsafe impl<U, V, T, Variants, TRest, Options>
+    FromVariants<CCons<Variation<V, T>, Variants>, TRest, Options> for U
+    where
+        T: blv::Add<TRest>,
+        U: FromLayout<blv::Sum<T, TRest>, Options>,
+        U: FromVariants<Variants, TRest, Options>,
+    {}
+
+    /// Implemented if each variant of the coproduct `Variants` (followed by
+    /// `TRest`) is transmutable into the variant of the coproduct `Self` with
+    /// the same discriminant (followed by `URest`).
+    pub unsafe trait VariantsFromVariants<Variants, TRest, URest, Options> {}
+
+    unsafe impl<U, TRest, URest, Options>
+    VariantsFromVariants<CNil, TRest, URest, Options> for U {}
+
+    #[rustfmt::skip] unsafe impl<U, V, T, Variants, TRest, URest, Options>
+    VariantsFromVariants<CCons<Variation<V, T>, Variants>, TRest, URest, Options> for U
+    where
+        U: Find<V>,
+        T: blv::Add<TRest>,
+        Found<U, V>: blv::Add<URest>,
+        blv::Sum<Found<U, V>, URest>: FromLayout<blv::Sum<T, TRest>, Options>,
+        U: VariantsFromVariants<Variants, TRest, URest, Options>,
+    {}
+
+    /// [Coproduct|_] -> [Coproduct|_]
+    #[rustfmt::skip] unsafe impl<TH, TT, TRest, UH, UT, URest, Options>
+    FromLayout<PCons<CCons<TH, TT>, TRest>, Options>
+         for PCons<CCons<UH, UT>, URest>
+    where
+        CCons<UH, UT>: VariantsFromVariants<CCons<TH, TT>, TRest, URest, Options>,
+    {}
+
+    /// [Coproduct|_] -> [Bytes|_], where the leading bytes are zero-sized.
+    #[rustfmt::skip] unsafe impl<TH, TT, TRest, UVis, UKind, URest, Options>
+    FromLayout<PCons<CCons<TH, TT>, TRest>, Options>
+         for PCons<Bytes<UVis, UKind, num::UTerm>, URest>
+    where
+        URest: FromLayout<PCons<CCons<TH, TT>, TRest>, Options>,
+    {}
+
+    /// [Coproduct|_] -> [Bytes|_]
+    #[rustfmt::skip] unsafe impl<TH, TT, TRest, UVis, UKind, A, B, URest, Options>
+    FromLayout<PCons<CCons<TH, TT>, TRest>, Options>
+         for PCons<Bytes<UVis, UKind, num::UInt<A, B>>, URest>
+    where
+        Self: FromVariants<CCons<TH, TT>, TRest, Options>,
+    {}
+
+    /// [Coproduct|_] -> [Array|_]
+    #[rustfmt::skip] unsafe impl<TH, TT, TRest, UVis, U, USize, URest, UTarget, Options>
+    FromLayout<PCons<CCons<TH, TT>, TRest>, Options>
+         for PCons<Array<UVis, U, USize, UTarget>, URest>
+    where
+        Self: FromVariants<CCons<TH, TT>, TRest, Options>,
+    {}
+
+    /// [Coproduct|_] -> [Reference|_]
+    #[rustfmt::skip] unsafe impl<'u, TH, TT, TRest, UVis, UK, U, URest, UTarget, Options>
+    FromLayout<PCons<CCons<TH, TT>, TRest>, Options>
+         for PCons<Reference<'u, UVis, UK, U, UTarget>, URest>
+    where
+        Self: FromVariants<CCons<TH, TT>, TRest, Options>,
+    {}
+
+    /// [Coproduct|_] -> [Opaque|_]
+    #[rustfmt::skip] unsafe impl<TH, TT, TRest, UVis, U, URest, Options>
+    FromLayout<PCons<CCons<TH, TT>, TRest>, Options>
+         for PCons<Opaque<UVis, U>, URest>
+    where
+        Self: FromVariants<CCons<TH, TT>, TRest, Options>,
+    {}
+}
 
 #[cfg(test)]
 mod test {
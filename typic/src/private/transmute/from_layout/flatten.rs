@@ -1,29 +1,148 @@
 use crate::private::num;
 
 use crate::private::layout::Layout;
-use crate::private::bytelevel::{self as blv, slot::Array, PCons};
+use crate::private::bytelevel::{
+    self as blv,
+    slot::{bytes::kind, Array, Bytes, Opaque, Reference},
+    CCons, PCons, PNil,
+};
 
 pub trait Flatten {
     type Output;
 }
 
-impl<Vis, T, TRest> Flatten for PCons<Array<Vis, T, num::UTerm>, TRest>
+impl<Vis, T, Target, TRest> Flatten for PCons<Array<Vis, T, num::UTerm, Target>, TRest>
 where
 {
     type Output = TRest;
 }
 
-impl<Vis, T, A, B, TRest> Flatten for PCons<Array<Vis, T, num::UInt<A, B>>, TRest>
+impl<Vis, T, A, B, Target, TRest> Flatten for PCons<Array<Vis, T, num::UInt<A, B>, Target>, TRest>
 where
-    T: Layout<Vis>,
-    num::UInt<A, B>: num::Sub<num::B1>,
+    T: Layout<Vis, Target>,
+    <T as Layout<Vis, Target>>::ByteLevel: FlattenElements<Vis, T, num::UInt<A, B>, Target, TRest>,
+{
+    type Output = <<T as Layout<Vis, Target>>::ByteLevel as FlattenElements<Vis, T, num::UInt<A, B>, Target, TRest>>::Output;
+}
+
+/// Flatten the `N` elements of an array of `T` on `Target` (whose byte-level
+/// layout is `Self`), followed by `TRest`.
+///
+/// An array of bytes that may be split is flattened into a single slot of
+/// bytes, so that long arrays are transmuted in one step; any other array is
+/// flattened one element at a time.
+pub trait FlattenElements<Vis, T, N, Target, TRest> {
+    type Output;
+}
+
+/// Peel the first element of an array off its remaining elements.
+type Peel<Vis, T, N, Target, TRest> =
+    blv::Sum<
+        <T as Layout<Vis, Target>>::ByteLevel,
+        PCons<Array<Vis, T, num::Sub1<N>, Target>, TRest>
+    >;
+
+/// An array of zero-sized elements is empty.
+impl<Vis, T, N, Target, TRest> FlattenElements<Vis, T, N, Target, TRest> for PNil {
+    type Output = TRest;
+}
 
-    <T as Layout<Vis>>::ByteLevel:
-      blv::Add<PCons<Array<Vis, T, num::Sub1<num::UInt<A, B>>>, TRest>>,
+impl<Vis, T, N, Target, TRest, H, R> FlattenElements<Vis, T, N, Target, TRest> for PCons<H, R>
+where
+    H: FlattenHead<R, Vis, T, N, Target, TRest>,
+{
+    type Output = <H as FlattenHead<R, Vis, T, N, Target, TRest>>::Output;
+}
+
+/// Flatten the elements of an array, whose byte-level layout is `Self`
+/// followed by `R`.
+pub trait FlattenHead<R, Vis, T, N, Target, TRest> {
+    type Output;
+}
+
+impl<R, Vis, T, N, Target, TRest, BVis, K, S> FlattenHead<R, Vis, T, N, Target, TRest> for Bytes<BVis, K, S>
+where
+    K: FlattenKind<R, BVis, S, Vis, T, N, Target, TRest>,
 {
-    type Output =
-      blv::Sum<
-        <T as Layout<Vis>>::ByteLevel,
-        PCons<Array<Vis, T, num::Sub1<num::UInt<A, B>>>, TRest>
-      >;
-}
\ No newline at end of file
+    type Output = <K as FlattenKind<R, BVis, S, Vis, T, N, Target, TRest>>::Output;
+}
+
+macro_rules! peel_head {
+    ($({$($G: tt)*} $head: ty;)*) => {
+        $(
+            impl<$($G)* R, Vis, T, N, Target, TRest> FlattenHead<R, Vis, T, N, Target, TRest> for $head
+            where
+                T: Layout<Vis, Target>,
+                N: num::Sub<num::B1>,
+                <T as Layout<Vis, Target>>::ByteLevel: blv::Add<PCons<Array<Vis, T, num::Sub1<N>, Target>, TRest>>,
+            {
+                type Output = Peel<Vis, T, N, Target, TRest>;
+            }
+        )*
+    };
+}
+
+peel_head! {
+    {AVis, AT, AN, ATarget,} Array<AVis, AT, AN, ATarget>;
+    {'a, RVis, RK, RT, RTarget,} Reference<'a, RVis, RK, RT, RTarget>;
+    {OVis, OT,} Opaque<OVis, OT>;
+    {CH, CT,} CCons<CH, CT>;
+}
+
+/// Flatten the elements of an array, whose byte-level layout is bytes of
+/// kind `Self`, visibility `BVis` and size `S`, followed by `R`.
+pub trait FlattenKind<R, BVis, S, Vis, T, N, Target, TRest> {
+    type Output;
+}
+
+macro_rules! merge_kind {
+    ($($kind: ty),*) => {
+        $(
+            /// These bytes may be split, so the array is a single slot of them.
+            impl<BVis, S, Vis, T, N, Target, TRest> FlattenKind<PNil, BVis, S, Vis, T, N, Target, TRest> for $kind
+            where
+                S: num::Mul<N>,
+            {
+                type Output = PCons<Bytes<BVis, $kind, num::Prod<S, N>>, TRest>;
+            }
+
+            impl<RH, RT, BVis, S, Vis, T, N, Target, TRest> FlattenKind<PCons<RH, RT>, BVis, S, Vis, T, N, Target, TRest> for $kind
+            where
+                T: Layout<Vis, Target>,
+                N: num::Sub<num::B1>,
+                <T as Layout<Vis, Target>>::ByteLevel: blv::Add<PCons<Array<Vis, T, num::Sub1<N>, Target>, TRest>>,
+            {
+                type Output = Peel<Vis, T, N, Target, TRest>;
+            }
+        )*
+    };
+}
+
+merge_kind!(
+    kind::Initialized,
+    kind::Uninitialized,
+    kind::Interior<kind::Initialized>,
+    kind::Interior<kind::Uninitialized>
+);
+
+macro_rules! peel_kind {
+    ($({$($G: tt)*} $kind: ty;)*) => {
+        $(
+            impl<$($G)* R, BVis, S, Vis, T, N, Target, TRest> FlattenKind<R, BVis, S, Vis, T, N, Target, TRest> for $kind
+            where
+                T: Layout<Vis, Target>,
+                N: num::Sub<num::B1>,
+                <T as Layout<Vis, Target>>::ByteLevel: blv::Add<PCons<Array<Vis, T, num::Sub1<N>, Target>, TRest>>,
+            {
+                type Output = Peel<Vis, T, N, Target, TRest>;
+            }
+        )*
+    };
+}
+
+peel_kind! {
+    {} kind::Char;
+    {Start, End,} kind::Range<Start, End>;
+    {} kind::Interior<kind::Char>;
+    {Start, End,} kind::Interior<kind::Range<Start, End>>;
+}
@@ -0,0 +1,94 @@
+//! A type without a defined layout occupies a single, indivisible slot. It
+//! may only be transmuted into a slot of the very same type, and only if the
+//! stability of layouts is neglected.
+use super::*;
+use crate::private::layout::Code;
+
+/// Implemented for types with a defined layout; i.e., for no type whose
+/// layout is [`Opaque`].
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` has no defined layout",
+    label = "`{Self}` has no defined layout",
+    note = "a type without a defined layout may only be transmuted into itself; consider giving it a `#[typic::repr(C)]`",
+)]
+pub trait DefinedLayout {}
+
+/// Implemented if `T`, a type without a defined layout, may be transmuted
+/// into `Self` with the given `Stability`.
+#[diagnostic::on_unimplemented(
+    message = "`{T}` has no defined layout",
+    label = "`{T}` has no defined layout",
+    note = "a type without a defined layout may only be transmuted into itself, and only if `neglect::Stability` is given",
+)]
+pub trait FromOpaque<T, Stability> {}
+
+impl<T> FromOpaque<T, Unstable> for T {}
+
+/// The code behind a function pointer is never read, so a function pointer
+/// may become a function pointer of the very same type even if stability is
+/// enforced.
+impl<F> FromOpaque<Code<F>, Stable> for Code<F> {}
+
+/// Implemented if bytes of visibility `T` may be transmuted into bytes of
+/// visibility `Self`.
+pub trait FromVisibility<T, Variance, Transparency> {}
+
+/// Regardless of variance and transparency, this `pub` to `pub` conversion is safe.
+impl<Variance, Transparency> FromVisibility<Pub, Variance, Transparency> for Pub {}
+
+/// A `priv` to `pub` conversion is safe only if the transmutation is variant.
+impl<Transparency> FromVisibility<Priv, Variant, Transparency> for Pub {}
+
+/// A `priv`/`pub` to `priv` conversion is only safe if transparency is unchecked.
+impl<TVis, Variance> FromVisibility<TVis, Variance, Unenforced> for Priv {}
+
+/// [Opaque|_] -> [Opaque|_]
+#[rustfmt::skip] unsafe impl<TVis, T, TRest, UVis, U, URest, Variance, Alignment, Transparency, Stability, Validity>
+FromLayout<PCons<Opaque<TVis, T>, TRest>, (Variance, Alignment, Transparency, Stability, Validity)>
+       for PCons<Opaque<UVis, U>, URest>
+where
+    U: FromOpaque<T, Stability>,
+    UVis: FromVisibility<TVis, Variance, Transparency>,
+    URest: FromLayout<TRest, (Variance, Alignment, Transparency, Stability, Validity)>,
+{}
+
+/// [Opaque|_] -> [Bytes|_], where the leading bytes are zero-sized.
+#[rustfmt::skip] unsafe impl<TVis, T, TRest, UVis, UKind, URest, Options>
+FromLayout<PCons<Opaque<TVis, T>, TRest>, Options>
+       for PCons<Bytes<UVis, UKind, UTerm>, URest>
+where
+    URest: FromLayout<PCons<Opaque<TVis, T>, TRest>, Options>,
+{}
+
+/// [Opaque|_] -> [Bytes|_]
+#[rustfmt::skip] unsafe impl<TVis, T, TRest, UVis, UKind, A, B, URest, Options>
+FromLayout<PCons<Opaque<TVis, T>, TRest>, Options>
+       for PCons<Bytes<UVis, UKind, UInt<A, B>>, URest>
+where
+    T: DefinedLayout,
+{}
+
+/// [Opaque|_] -> [Array|_]
+#[rustfmt::skip] unsafe impl<TVis, T, TRest, UVis, U, USize, URest, UTarget, Options>
+FromLayout<PCons<Opaque<TVis, T>, TRest>, Options>
+       for PCons<Array<UVis, U, USize, UTarget>, URest>
+where
+    Self: Flatten,
+    <Self as Flatten>::Output: FromLayout<PCons<Opaque<TVis, T>, TRest>, Options>,
+{}
+
+/// [Opaque|_] -> [Reference|_]
+#[rustfmt::skip] unsafe impl<'u, TVis, T, TRest, UVis, UK, U, URest, UTarget, Options>
+FromLayout<PCons<Opaque<TVis, T>, TRest>, Options>
+       for PCons<Reference<'u, UVis, UK, U, UTarget>, URest>
+where
+    T: DefinedLayout,
+{}
+
+/// [Opaque|_] -> [Coproduct|_]
+#[rustfmt::skip] unsafe impl<TVis, T, TRest, UH, UT, URest, Options>
+FromLayout<PCons<Opaque<TVis, T>, TRest>, Options>
+       for PCons<CCons<UH, UT>, URest>
+where
+    T: DefinedLayout,
+{}
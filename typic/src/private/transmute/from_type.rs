@@ -1,10 +1,11 @@
 use crate::stability::*;
 use crate::private::layout::Layout;
+use crate::private::target::Host;
 use crate::internal::{Public, Private};
 use super::{Stable, Unstable, from_layout::FromLayout};
 
 /// A marker trait implemented if every instance of `T` is transmutable into
-/// an instance of `Self`.
+/// an instance of `Self` on `Target`.
 pub unsafe trait FromType<
   SourceType,
   // Can bit-validity be widened?
@@ -17,14 +18,16 @@ pub unsafe trait FromType<
   Stability,
   /// Must all values of the source type be a valid instance of the destination type?
   Validity,
+  /// The target whose layouts are compared.
+  Target = Host,
 >{}
 
-unsafe impl<T, U, Variance, Alignment, Transparency, Validity>
-FromType<T, Variance, Alignment, Transparency, Unstable, Validity> for U
+unsafe impl<T, U, Variance, Alignment, Transparency, Validity, Target>
+FromType<T, Variance, Alignment, Transparency, Unstable, Validity, Target> for U
 where
-    T: Layout<Public>,
-    U: Layout<Public>,
-    <U as Layout<Public>>::ByteLevel: FromLayout<<T as Layout<Public>>::ByteLevel,
+    T: Layout<Public, Target>,
+    U: Layout<Public, Target>,
+    <U as Layout<Public, Target>>::ByteLevel: FromLayout<<T as Layout<Public, Target>>::ByteLevel,
       (Variance,
       Alignment,
       Transparency,
@@ -32,25 +35,27 @@ where
       Validity,)>
 {}
 
-unsafe impl<T, U, Variance, Alignment, Transparency, Validity>
-FromType<T, Variance, Alignment, Transparency, Stable, Validity> for U
+unsafe impl<T, U, Variance, Alignment, Transparency, Validity, Target>
+FromType<T, Variance, Alignment, Transparency, Stable, Validity, Target> for U
 where
-    T: TransmutableFrom + Layout<Public>,
-    U: TransmutableInto + Layout<Public>,
+    T: TransmutableInto + Layout<Public, Target>,
+    U: TransmutableFrom + Layout<Public, Target>,
 
     // If stability is being enforced, then
-    // the widest extent of the source type
-    // must be transmutable into the narrowest
-    // extent of the destination type.
-    <U as TransmutableInto>::Type:
-      FromType<<T as TransmutableFrom>::Type,
+    // what the source type promises to be
+    // transmutable into must be transmutable
+    // into what the destination type promises
+    // to be transmutable from.
+    <U as TransmutableFrom>::Type:
+      FromType<<T as TransmutableInto>::Type,
         Variance,
         Alignment,
         Transparency,
         Unstable,
-        Validity>,
+        Validity,
+        Target>,
 
-    <U as Layout<Public>>::ByteLevel: FromLayout<<T as Layout<Public>>::ByteLevel,
+    <U as Layout<Public, Target>>::ByteLevel: FromLayout<<T as Layout<Public, Target>>::ByteLevel,
       (Variance,
       Alignment,
       Transparency,
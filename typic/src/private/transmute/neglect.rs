@@ -1,3 +1,5 @@
+use core::marker::PhantomData;
+
 /// Neglect statically guaranteeing pointer alignments.
 ///
 /// By default, Typic ***statically requires*** that, when transmuting
@@ -46,8 +48,6 @@ pub struct Stability;
 /// Neglect statically guaranteeing that all instances of the source type are bit-valid
 /// instances of the destination type.
 ///
-/// (Typic does not currently fully implement this, but will soon.)
-/// 
 /// By default, Typic only accepts transmutations for which all possible
 /// values of the source type are bit-valid values of the destination
 /// type.  (This means no `u8 → bool` transmutes!)
@@ -55,42 +55,52 @@ pub struct Stability;
 /// If you have special knowledge about the value (e.g., because you've
 /// ensured at runtime that it's a bit-valid instance of the destination
 /// type), you can opt-out of this guarantee by asserting
-/// `T: UnsafeTransmuteInto<U, neglect::Visibility>`.
-/// 
+/// `T: UnsafeTransmuteInto<U, neglect::Validity>`:
+/// ```
+/// # use typic::docs::prelude::*;
+/// use typic::transmute::neglect;
+///
+/// let _: NonZeroU8 = unsafe { unsafe_transmute::<u8, _, neglect::Validity>(42) };
+/// ```
+///
 /// Typic will still reject transmutations that cannot possibly be valid
 /// for any value, e.g.:
-/// ```ignore
-/// #[typic::repr(C)] enum Foo { N = 24 }
-/// #[typic::repr(C)] enum Bar { N = 24 }
-/// 
-/// let bar : Bar = (Foo::N).unsafe_transmute_into() // Compile error!
+/// ```compile_fail
+/// # use typic::docs::prelude::*;
+/// use typic::transmute::neglect;
+///
+/// #[typic::repr(u8)] #[derive(StableABI)] enum Foo { N = 24 }
+/// #[typic::repr(u8)] #[derive(StableABI)] enum Bar { N = 25 }
+///
+/// let _: Bar = unsafe { unsafe_transmute::<_, _, neglect::Validity>(Foo::N) }; // Compile error!
+/// ```
+/// ...or that would expose uninitialized bytes as initialized:
+/// ```compile_fail
+/// # use typic::docs::prelude::*;
+/// use typic::transmute::neglect;
+///
+/// let _: Packed = unsafe { unsafe_transmute::<_, _, neglect::Validity>(Padded::default()) }; // Compile error!
 /// ```
 pub struct Validity;
 
-/// Options for safe and unsafe transmutation.
-pub trait TransmuteOptions: UnsafeTransmuteOptions {
-    type Stability;
-}
+/// Options for safe transmutation: those neglecting nothing but
+/// [`Stability`].
+pub trait TransmuteOptions: UnsafeTransmuteOptions {}
 
-impl TransmuteOptions for () {
-    type Stability    = super::Stable;
-}
-
-impl TransmuteOptions for Stability {
-    type Stability    = super::Unstable;
-}
-
-impl<O> UnsafeTransmuteOptions for O
+impl<O> TransmuteOptions for O
 where
-    O: TransmuteOptions
-{
-    type Alignment      = super::Static;
-    type Transparency   = super::Enforced;
-    type Stability      = <O as TransmuteOptions>::Stability;
-    type Validity       = super::AlwaysValid;
-}
+    O: UnsafeTransmuteOptions<
+        Alignment    = super::Static,
+        Transparency = super::Enforced,
+        Validity     = super::AlwaysValid,
+    >,
+{}
 
 /// Options for unsafe transmutation.
+///
+/// Options are neglected singly (e.g., `neglect::Alignment`), or together as
+/// a tuple, in any order (e.g., `(neglect::Validity, neglect::Alignment)`).
+/// Tuples may nest, and the empty tuple neglects nothing.
 pub trait UnsafeTransmuteOptions {
     type Alignment;
     type Transparency;
@@ -98,122 +108,63 @@ pub trait UnsafeTransmuteOptions {
     type Validity;
 }
 
-impl UnsafeTransmuteOptions for Alignment {
-    type Alignment    = super::Unchecked;
-    type Stability    = super::Stable;
-    type Transparency = super::Enforced;
-    type Validity     = super::AlwaysValid;
-}
-
-impl UnsafeTransmuteOptions for (Alignment,) {
-    type Alignment    = super::Unchecked;
-    type Stability    = super::Stable;
-    type Transparency = super::Enforced;
-    type Validity     = super::AlwaysValid;
-}
-
-impl UnsafeTransmuteOptions for Transparency {
-    type Alignment    = super::Static;
-    type Stability    = super::Stable;
-    type Transparency = super::Unenforced;
-    type Validity     = super::AlwaysValid;
-}
-
-impl UnsafeTransmuteOptions for (Transparency,) {
-    type Alignment    = super::Static;
-    type Stability    = super::Stable;
-    type Transparency = super::Unenforced;
-    type Validity     = super::AlwaysValid;
-}
-
-impl UnsafeTransmuteOptions for Validity {
-    type Alignment    = super::Static;
-    type Stability    = super::Stable;
-    type Transparency = super::Unenforced;
-    type Validity     = super::AlwaysValid;
-}
-
-impl UnsafeTransmuteOptions for (Validity,) {
-    type Alignment    = super::Static;
-    type Stability    = super::Stable;
-    type Transparency = super::Enforced;
-    type Validity     = super::MaybeInvalid;
-}
-
-impl UnsafeTransmuteOptions for (Alignment, Transparency) {
-    type Alignment    = <Alignment as UnsafeTransmuteOptions>::Alignment;
-    type Stability    = <Stability as UnsafeTransmuteOptions>::Stability;
-    type Transparency = <Transparency as UnsafeTransmuteOptions>::Transparency;
-    type Validity     = <Validity as UnsafeTransmuteOptions>::Validity;
+impl<O, Alignment, Transparency, Stability, Validity> UnsafeTransmuteOptions for O
+where
+    O: Union<Neglect, Output = Neglect<Alignment, Transparency, Stability, Validity>>,
+{
+    type Alignment    = Alignment;
+    type Transparency = Transparency;
+    type Stability    = Stability;
+    type Validity     = Validity;
 }
 
-impl UnsafeTransmuteOptions for (Alignment, Stability) {
-    type Alignment    = <Alignment as UnsafeTransmuteOptions>::Alignment;
-    type Stability    = <Stability as UnsafeTransmuteOptions>::Stability;
-    type Transparency = <Transparency as UnsafeTransmuteOptions>::Transparency;
-    type Validity     = <Validity as UnsafeTransmuteOptions>::Validity;
-}
+/// A set of neglected options. Each parameter is either the check that is
+/// made by default, or its neglected counterpart.
+pub struct Neglect<
+    Alignment    = super::Static,
+    Transparency = super::Enforced,
+    Stability    = super::Stable,
+    Validity     = super::AlwaysValid,
+>(PhantomData<(Alignment, Transparency, Stability, Validity)>);
 
-impl UnsafeTransmuteOptions for (Alignment, Validity) {
-    type Alignment    = <Alignment as UnsafeTransmuteOptions>::Alignment;
-    type Stability    = <Stability as UnsafeTransmuteOptions>::Stability;
-    type Transparency = <Transparency as UnsafeTransmuteOptions>::Transparency;
-    type Validity     = <Validity as UnsafeTransmuteOptions>::Validity;
+/// The union of the options neglected by `Self` and the set `Set`.
+pub trait Union<Set> {
+    type Output;
 }
 
-impl UnsafeTransmuteOptions for (Transparency, Stability) {
-    type Alignment    = <Alignment as UnsafeTransmuteOptions>::Alignment;
-    type Stability    = <Stability as UnsafeTransmuteOptions>::Stability;
-    type Transparency = <Transparency as UnsafeTransmuteOptions>::Transparency;
-    type Validity     = <Validity as UnsafeTransmuteOptions>::Validity;
+impl<A, T, S, V> Union<Neglect<A, T, S, V>> for Alignment {
+    type Output = Neglect<super::Unchecked, T, S, V>;
 }
 
-impl UnsafeTransmuteOptions for (Transparency, Validity) {
-    type Alignment    = <Alignment as UnsafeTransmuteOptions>::Alignment;
-    type Stability    = <Stability as UnsafeTransmuteOptions>::Stability;
-    type Transparency = <Transparency as UnsafeTransmuteOptions>::Transparency;
-    type Validity     = <Validity as UnsafeTransmuteOptions>::Validity;
+impl<A, T, S, V> Union<Neglect<A, T, S, V>> for Transparency {
+    type Output = Neglect<A, super::Unenforced, S, V>;
 }
 
-impl UnsafeTransmuteOptions for (Stability, Validity) {
-    type Alignment    = <Alignment as UnsafeTransmuteOptions>::Alignment;
-    type Stability    = <Stability as UnsafeTransmuteOptions>::Stability;
-    type Transparency = <Transparency as UnsafeTransmuteOptions>::Transparency;
-    type Validity     = <Validity as UnsafeTransmuteOptions>::Validity;
+impl<A, T, S, V> Union<Neglect<A, T, S, V>> for Stability {
+    type Output = Neglect<A, T, super::Unstable, V>;
 }
 
-impl UnsafeTransmuteOptions for (Alignment, Transparency, Stability) {
-    type Alignment    = <Alignment as UnsafeTransmuteOptions>::Alignment;
-    type Stability    = <Stability as UnsafeTransmuteOptions>::Stability;
-    type Transparency = <Transparency as UnsafeTransmuteOptions>::Transparency;
-    type Validity     = <Validity as UnsafeTransmuteOptions>::Validity;
+impl<A, T, S, V> Union<Neglect<A, T, S, V>> for Validity {
+    type Output = Neglect<A, T, S, super::MaybeInvalid>;
 }
 
-impl UnsafeTransmuteOptions for (Alignment, Transparency, Validity) {
-    type Alignment    = <Alignment as UnsafeTransmuteOptions>::Alignment;
-    type Stability    = <Stability as UnsafeTransmuteOptions>::Stability;
-    type Transparency = <Transparency as UnsafeTransmuteOptions>::Transparency;
-    type Validity     = <Validity as UnsafeTransmuteOptions>::Validity;
+impl<Set> Union<Set> for () {
+    type Output = Set;
 }
 
-impl UnsafeTransmuteOptions for (Alignment, Stability, Validity) {
-    type Alignment    = <Alignment as UnsafeTransmuteOptions>::Alignment;
-    type Stability    = <Stability as UnsafeTransmuteOptions>::Stability;
-    type Transparency = <Transparency as UnsafeTransmuteOptions>::Transparency;
-    type Validity     = <Validity as UnsafeTransmuteOptions>::Validity;
-}
+macro_rules! tuple_union {
+    ($Head: ident $(, $Tail: ident)*) => {
+        impl<Set, $Head $(, $Tail)*> Union<Set> for ($Head, $($Tail,)*)
+        where
+            $Head: Union<Set>,
+            ($($Tail,)*): Union<<$Head as Union<Set>>::Output>,
+        {
+            type Output = <($($Tail,)*) as Union<<$Head as Union<Set>>::Output>>::Output;
+        }
 
-impl UnsafeTransmuteOptions for (Transparency, Stability, Validity) {
-    type Alignment    = <Alignment as UnsafeTransmuteOptions>::Alignment;
-    type Stability    = <Stability as UnsafeTransmuteOptions>::Stability;
-    type Transparency = <Transparency as UnsafeTransmuteOptions>::Transparency;
-    type Validity     = <Validity as UnsafeTransmuteOptions>::Validity;
+        tuple_union!($($Tail),*);
+    };
+    () => {};
 }
 
-
-impl UnsafeTransmuteOptions for (Alignment, Transparency, Stability, Validity) {
-    type Alignment    = <Alignment as UnsafeTransmuteOptions>::Alignment;
-    type Stability    = <Stability as UnsafeTransmuteOptions>::Stability;
-    type Transparency = <Transparency as UnsafeTransmuteOptions>::Transparency;
-    type Validity     = <Validity as UnsafeTransmuteOptions>::Validity;
-}
\ No newline at end of file
+tuple_union!(O0, O1, O2, O3, O4, O5, O6, O7);
@@ -0,0 +1,551 @@
+//! Check, at runtime, that one layout is transmutable into another.
+//!
+//! The [runtime descriptions][crate::layout::describe] of the source and
+//! destination layouts are walked side by side, applying the same rules as
+//! [`FromLayout`][super::from_layout::FromLayout]. If a value of the source
+//! type is given, validity restrictions of the destination that the source
+//! does not statically satisfy are checked against that value's bytes.
+use super::explain::{Divergence, Location, Reason};
+use super::neglect::UnsafeTransmuteOptions;
+use super::{AlwaysValid, Enforced, MaybeInvalid, Stable, Static, Unchecked, Unenforced, Unstable};
+use crate::layout::{Description, Kind, Segment, Segments, Value, Variant, Visibility};
+use crate::private::target::{Endian, Host, Target};
+
+/// The static checks to perform.
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct Options {
+    pub(crate) variant: bool,
+    /// Whether the bytes are shared, so must keep their interior mutability.
+    pub(crate) aliased: bool,
+    /// Whether the bytes are those of a referent, so may not become public
+    /// if private, nor keep their variance if they become interior mutable.
+    pub(crate) referent: bool,
+    pub(crate) alignment: bool,
+    pub(crate) transparency: bool,
+    pub(crate) stable: bool,
+    pub(crate) validity: bool,
+}
+
+impl Options {
+    /// The checks performed for the options `O`.
+    pub(crate) fn new<O>() -> Self
+    where
+        O: UnsafeTransmuteOptions,
+        O::Alignment: CheckAlignment,
+        O::Transparency: CheckTransparency,
+        O::Stability: CheckStability,
+        O::Validity: CheckValidity,
+    {
+        Options {
+            variant: true,
+            aliased: false,
+            referent: false,
+            alignment: <O::Alignment as CheckAlignment>::CHECKED,
+            transparency: <O::Transparency as CheckTransparency>::ENFORCED,
+            stable: <O::Stability as CheckStability>::STABLE,
+            validity: <O::Validity as CheckValidity>::ENFORCED,
+        }
+    }
+}
+
+/// Is the alignment of referents checked?
+#[doc(hidden)]
+pub trait CheckAlignment {
+    const CHECKED: bool;
+}
+
+impl CheckAlignment for Static {
+    const CHECKED: bool = true;
+}
+
+impl CheckAlignment for Unchecked {
+    const CHECKED: bool = false;
+}
+
+/// Is the visibility of fields enforced?
+#[doc(hidden)]
+pub trait CheckTransparency {
+    const ENFORCED: bool;
+}
+
+impl CheckTransparency for Enforced {
+    const ENFORCED: bool = true;
+}
+
+impl CheckTransparency for Unenforced {
+    const ENFORCED: bool = false;
+}
+
+/// Must the source and destination types have stable ABIs?
+#[doc(hidden)]
+pub trait CheckStability {
+    const STABLE: bool;
+}
+
+impl CheckStability for Stable {
+    const STABLE: bool = true;
+}
+
+impl CheckStability for Unstable {
+    const STABLE: bool = false;
+}
+
+/// Must every value of the source be a valid value of the destination?
+#[doc(hidden)]
+pub trait CheckValidity {
+    const ENFORCED: bool;
+}
+
+impl CheckValidity for AlwaysValid {
+    const ENFORCED: bool = true;
+}
+
+impl CheckValidity for MaybeInvalid {
+    const ENFORCED: bool = false;
+}
+
+/// Check that the layout `t` is transmutable into the layout `u`.
+///
+/// If `value` points to a value of layout `t`, any validity restrictions of
+/// `u` that are not enforced by `options` are checked against its bytes.
+///
+/// # Safety
+/// `value`, if given, must point to a valid value of layout `t`.
+pub(crate) unsafe fn check(
+    t: &Description,
+    u: &Description,
+    options: Options,
+    value: Option<*const u8>,
+) -> Result<(), Divergence> {
+    walk(
+        Stream::new(t.segments(), 0, None),
+        Stream::new(u.segments(), 0, None),
+        0,
+        options,
+        value,
+    )
+}
+
+/// The remaining segments of a layout, with arrays flattened.
+#[derive(Clone, Copy)]
+struct Stream<'a> {
+    segments: Segments,
+    /// The number of bytes already consumed from the leading segment.
+    consumed: usize,
+    /// Whether the leading segment, a reference, is treated as bytes.
+    lowered: bool,
+    /// The segments to repeat once `segments` is exhausted.
+    element: Segments,
+    repeats: usize,
+    /// The stream to resume once this one is exhausted.
+    parent: Option<&'a Stream<'a>>,
+}
+
+impl<'a> Stream<'a> {
+    fn new(segments: Segments, repeats: usize, parent: Option<&'a Stream<'a>>) -> Self {
+        Stream {
+            segments,
+            consumed: 0,
+            lowered: false,
+            element: segments,
+            repeats,
+            parent,
+        }
+    }
+
+    /// The leading segment, less any bytes already consumed from it.
+    ///
+    /// Once bytes have been consumed from a value in the source, the
+    /// remainder of the value is merely initialized.
+    fn head(&mut self, source: bool) -> Option<Segment> {
+        loop {
+            if let Some(mut head) = self.segments.get(0) {
+                head.size -= self.consumed;
+                match head.kind {
+                    Kind::Value(_) | Kind::Range { .. } | Kind::Char
+                        if source && self.consumed > 0 =>
+                    {
+                        head.kind = Kind::Initialized
+                    }
+                    // The remainder of a signed value is only a bit pattern.
+                    Kind::Value(value) if self.consumed > 0 => {
+                        let bits = value.bits(head.size + self.consumed);
+                        head.kind = Kind::Value(Value::Unsigned(bits))
+                    }
+                    Kind::Reference { .. } if self.lowered => {
+                        head.kind = Kind::Range { start: 1, end: usize::MAX as u128 }
+                    }
+                    _ => {}
+                }
+                return Some(head);
+            } else if self.repeats > 0 {
+                self.segments = self.element;
+                self.repeats -= 1;
+            } else if let Some(parent) = self.parent {
+                *self = *parent;
+            } else {
+                return None;
+            }
+        }
+    }
+
+    /// Consume `size` bytes of the leading segment.
+    fn consume(&mut self, size: usize) {
+        self.consumed += size;
+        if self.segments.get(0).map_or(false, |head| head.size == self.consumed) {
+            *self = self.rest();
+        }
+    }
+
+    /// This stream, without its leading segment.
+    fn rest(&self) -> Self {
+        let mut rest = *self;
+        rest.segments.next();
+        rest.consumed = 0;
+        rest.lowered = false;
+        rest
+    }
+}
+
+/// Check that the remainder of the source, `t`, beginning at `offset`, is
+/// transmutable into the remainder of the destination, `u`.
+unsafe fn walk(
+    mut t: Stream<'_>,
+    mut u: Stream<'_>,
+    mut offset: usize,
+    options: Options,
+    value: Option<*const u8>,
+) -> Result<(), Divergence> {
+    loop {
+        let u_head = match u.head(false) {
+            Some(head) => head,
+            None => return Ok(()),
+        };
+
+        if let Kind::Array { element, count } = u_head.kind {
+            let rest = u.rest();
+            let u = Stream::new(element.segments(), count - 1, Some(&rest));
+            return walk(t, u, offset, options, value);
+        }
+
+        let diverge = |reason, source: Option<Segment>| Divergence {
+            offset,
+            reason,
+            source: source.map(|segment| segment.kind),
+            destination: u_head.kind,
+            location: Location::Value,
+        };
+
+        let t_head = match t.head(true) {
+            Some(head) => head,
+            None => return Err(diverge(Reason::Size, None)),
+        };
+
+        match t_head.kind {
+            Kind::Array { element, count } => {
+                let rest = t.rest();
+                let t = Stream::new(element.segments(), count - 1, Some(&rest));
+                return walk(t, u, offset, options, value);
+            }
+            Kind::Variants(variants) => {
+                let t_rest = t.rest();
+                let u_rest = u.rest();
+                for variant in variants {
+                    // Only the variant of the given value need be checked.
+                    if let Some(value) = value {
+                        if !is_variant(value, offset, variant) {
+                            continue;
+                        }
+                    }
+                    let t = Stream::new(variant.segments, 0, Some(&t_rest));
+                    match u_head.kind {
+                        Kind::Variants(mut u_variants) => {
+                            match u_variants.find(|v| v.discriminant == variant.discriminant) {
+                                Some(u_variant) => {
+                                    let u = Stream::new(u_variant.segments, 0, Some(&u_rest));
+                                    walk(t, u, offset, options, value)?
+                                }
+                                None => {
+                                    return Err(Divergence {
+                                        source: Some(discriminant(variant)),
+                                        ..diverge(Reason::Validity, None)
+                                    })
+                                }
+                            }
+                        }
+                        _ => walk(t, u, offset, options, value)?,
+                    }
+                }
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        match (t_head.kind, u_head.kind) {
+            (Kind::Opaque(t_type), Kind::Opaque(u_type)) => {
+                if t_type != u_type {
+                    return Err(diverge(Reason::Opaque, Some(t_head)));
+                }
+                if options.stable && !t_type.is_stable() {
+                    return Err(diverge(Reason::Stability, Some(t_head)));
+                }
+                visibility(t_head.visibility, u_head.visibility, options)
+                    .map_err(|reason| diverge(reason, Some(t_head)))?;
+            }
+            (Kind::Opaque(_), _) | (_, Kind::Opaque(_)) => {
+                return Err(diverge(Reason::Opaque, Some(t_head)));
+            }
+            (
+                Kind::Reference { unique: t_unique, owned: t_owned, referent: t_referent },
+                Kind::Reference { unique: u_unique, owned: u_owned, referent: u_referent },
+            ) => {
+                if u_unique && !t_unique {
+                    return Err(diverge(Reason::Mutability, Some(t_head)));
+                }
+                if u_owned && !t_owned {
+                    return Err(diverge(Reason::Ownership, Some(t_head)));
+                }
+                if u_owned
+                    && (u_referent.size() != t_referent.size()
+                        || u_referent.align() != t_referent.align())
+                {
+                    return Err(diverge(Reason::Deallocation, Some(t_head)));
+                }
+                if options.alignment && u_referent.align() > t_referent.align() {
+                    return Err(diverge(Reason::Alignment, Some(t_head)));
+                }
+                if options.aliased && t_head.interior != u_head.interior {
+                    return Err(diverge(Reason::InteriorMutability, Some(t_head)));
+                }
+                // The validity of referents is never checked at runtime. The
+                // referent of a shared reference is shared, as is everything
+                // reached through it; its validity may expand, unless the
+                // reference may be overwritten (through a unique reference,
+                // or a shared cell). A unique reference that becomes a shared
+                // reference is no longer mutated through, except through
+                // cells, nor is any unique reference reached through it.
+                let exclusive = options.referent && options.variant && !options.aliased;
+                let (variant, aliased) = match (t_unique, u_unique) {
+                    _ if (options.aliased || exclusive) && u_head.interior => (false, true),
+                    _ if options.aliased => (options.variant, true),
+                    (false, _) => (options.variant, true),
+                    (true, _) if exclusive => (true, false),
+                    (true, false) if options.variant => (true, false),
+                    (true, false) => (false, true),
+                    (true, true) => (false, false),
+                };
+                let referents =
+                    Options { variant, aliased, referent: true, validity: true, ..options };
+                check(t_referent, u_referent, referents, None).map_err(|divergence| Divergence {
+                    offset,
+                    location: match divergence.location {
+                        Location::Value => Location::Referent,
+                        location => location,
+                    },
+                    ..divergence
+                })?;
+            }
+            (Kind::Reference { .. }, _) => {
+                // A reference is otherwise treated as non-zero bytes.
+                t.lowered = true;
+                continue;
+            }
+            (Kind::Padding, Kind::Reference { .. }) | (Kind::Padding, Kind::Variants(_)) => {
+                return Err(diverge(Reason::Padding, Some(t_head)));
+            }
+            (_, Kind::Variants(variants)) if !options.validity => {
+                // The bytes may be those of any variant. If they are those of
+                // none, report the variant they came closest to.
+                let rest = u.rest();
+                let mut furthest: Option<Divergence> = None;
+                for variant in variants {
+                    let u = Stream::new(variant.segments, 0, Some(&rest));
+                    match walk(t, u, offset, options, value) {
+                        Ok(()) => return Ok(()),
+                        Err(divergence) => {
+                            if furthest.map_or(true, |furthest| divergence.offset > furthest.offset) {
+                                furthest = Some(divergence);
+                            }
+                        }
+                    }
+                }
+                return Err(furthest.unwrap_or_else(|| diverge(Reason::Validity, Some(t_head))));
+            }
+            (_, Kind::Reference { .. }) | (_, Kind::Variants(_)) => {
+                return Err(diverge(Reason::Validity, Some(t_head)));
+            }
+            (_, _) => {
+                let size = t_head.size.min(u_head.size);
+                // The bytes of a referent that are interior mutable may be
+                // written.
+                let options = Options {
+                    variant: options.variant && !(options.referent && u_head.interior),
+                    ..options
+                };
+                // Invalid values are reported at the first of their bytes.
+                let mut start = offset;
+
+                bytes(t_head, u_head, options)
+                    .and_then(|checked| match (checked, value) {
+                        (Checked::Statically, _) | (Checked::AtRuntime, None) => Ok(()),
+                        (Checked::AtRuntime, Some(value)) => {
+                            let skip = u.consumed;
+                            if is_valid(value, offset, size, u_head, skip) {
+                                Ok(())
+                            } else {
+                                start = offset - skip;
+                                Err(Reason::Validity)
+                            }
+                        }
+                    })
+                    .and_then(|()| visibility(t_head.visibility, u_head.visibility, options))
+                    .map_err(|reason| Divergence { offset: start, ..diverge(reason, Some(t_head)) })?;
+
+                t.consume(size);
+                u.consume(size);
+                offset += size;
+                continue;
+            }
+        }
+
+        // The leading segments of `t` and `u` were wholly transmutable.
+        t.consume(t_head.size);
+        u.consume(u_head.size);
+        offset += u_head.size;
+    }
+}
+
+/// When bytes of the source are known to be valid for the destination.
+enum Checked {
+    /// Every value of the source bytes is valid.
+    Statically,
+    /// Some values of the source bytes are valid, if validity is not
+    /// enforced statically.
+    AtRuntime,
+}
+
+/// Check that bytes of the leading segment of the source are transmutable
+/// into bytes of the leading segment of the destination.
+fn bytes(t: Segment, u: Segment, options: Options) -> Result<Checked, Reason> {
+    if options.aliased && t.interior != u.interior {
+        return Err(Reason::InteriorMutability);
+    }
+    let widen = || {
+        if options.variant {
+            Ok(Checked::Statically)
+        } else {
+            Err(Reason::Invariance)
+        }
+    };
+    let narrow = || {
+        if options.validity {
+            Err(Reason::Validity)
+        } else {
+            Ok(Checked::AtRuntime)
+        }
+    };
+    match (t.kind, u.kind) {
+        (Kind::Padding, Kind::Padding) | (Kind::Initialized, Kind::Initialized) => {
+            Ok(Checked::Statically)
+        }
+        (Kind::Padding, _) => Err(Reason::Padding),
+        (_, Kind::Padding) | (_, Kind::Initialized) => widen(),
+        // A `char`, or a value of a range, may only become a whole `char`, or
+        // a whole value of a range.
+        (Kind::Char, Kind::Char) if t.size == u.size => Ok(Checked::Statically),
+        _ => match (range(t), range(u)) {
+            (Some(t_range), Some(u_range)) if t.size == u.size && t_range == u_range => {
+                Ok(Checked::Statically)
+            }
+            (Some((t_start, t_end)), Some((u_start, u_end)))
+                if t.size == u.size && u_start <= t_start && t_end <= u_end =>
+            {
+                widen().or_else(|_| narrow())
+            }
+            _ => narrow(),
+        },
+    }
+}
+
+/// The inclusive range of values of the bytes of `segment`, if they are
+/// restricted to one.
+fn range(segment: Segment) -> Option<(u128, u128)> {
+    match segment.kind {
+        Kind::Value(value) => Some((value.bits(segment.size), value.bits(segment.size))),
+        Kind::Range { start, end } => Some((start, end)),
+        _ => None,
+    }
+}
+
+/// Check that bytes of visibility `t` are transmutable into bytes of
+/// visibility `u`.
+fn visibility(t: Visibility, u: Visibility, options: Options) -> Result<(), Reason> {
+    match (t, u) {
+        (_, Visibility::Private) if options.transparency => Err(Reason::IntoPrivate),
+        (Visibility::Private, Visibility::Public) if !options.variant || options.referent => {
+            Err(Reason::PrivateIntoPublic)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Whether the `size` bytes of `value` at `offset` are valid bytes of the
+/// segment `u`, of which `skip` bytes precede them.
+unsafe fn is_valid(value: *const u8, offset: usize, size: usize, u: Segment, skip: usize) -> bool {
+    // The bytes of a `char` or a range can only be checked once all of them
+    // are in hand.
+    if size < u.size {
+        return true;
+    }
+    let width = skip + u.size;
+    let start = value.add(offset - skip);
+    match u.kind {
+        Kind::Char => {
+            char::from_u32(u32::from_ne_bytes(start.cast::<[u8; 4]>().read_unaligned())).is_some()
+        }
+        _ => match range(u) {
+            Some((low, high)) => {
+                let value = (0..width).fold(0, |value, index| {
+                    value | (start.add(index).read() as u128) << (8 * shift(width, index))
+                });
+                low <= value && value <= high
+            }
+            None => true,
+        },
+    }
+}
+
+/// The discriminant of `variant`, as the value its leading bytes must have.
+fn discriminant(variant: Variant) -> Kind {
+    match variant.segments.get(0) {
+        Some(Segment { kind: kind @ Kind::Value(_), .. }) => kind,
+        _ => Kind::Value(Value::Unsigned(variant.discriminant)),
+    }
+}
+
+/// Whether `value` is of the given `variant` of the enum at `offset`.
+unsafe fn is_variant(value: *const u8, offset: usize, variant: Variant) -> bool {
+    match variant.segments.get(0) {
+        Some(Segment { kind: Kind::Value(discriminant), size, .. }) => {
+            let discriminant = discriminant.bits(size);
+            (0..size).all(|index| value.add(offset + index).read() == value_byte(discriminant, size, index))
+        }
+        _ => true,
+    }
+}
+
+/// The `index`th byte in memory of the `width`-byte integer `value`.
+fn value_byte(value: u128, width: usize, index: usize) -> u8 {
+    (value >> (8 * shift(width, index))) as u8
+}
+
+/// The significance, in bytes, of the `index`th byte in memory of a
+/// `width`-byte integer.
+fn shift(width: usize, index: usize) -> usize {
+    if <<Host as Target>::Endian as Endian>::LITTLE {
+        index
+    } else {
+        width - 1 - index
+    }
+}
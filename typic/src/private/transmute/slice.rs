@@ -0,0 +1,298 @@
+//! Transmutations between slices, whose lengths are computed from the sizes
+//! of their elements.
+use super::from_type::FromType;
+use super::neglect::{TransmuteOptions, UnsafeTransmuteOptions};
+use super::{AlwaysValid, Enforced, Unchecked, Variant};
+use crate::private::layout::Layout;
+use crate::private::num::{Div, Min, Minimum, Quot, Rem, U0};
+use core::{fmt, mem, slice};
+use generic_array::{ArrayLength, GenericArray};
+
+/// A ***safe*** and ***sound*** conversion of a slice into a slice of
+/// another element type, which checks at runtime that the slice's address
+/// and size suit the destination.
+///
+/// `TransmuteSliceInto<Dst>` is implemented for `&[T]`, `&mut [T]`,
+/// `Box<[T]>` (with the `alloc` feature) and `&str`, and for destinations
+/// `&[U]`, `&mut [U]` and `Box<[U]>` of compatible mutability, when:
+/// 1. the size of `T` is a multiple or a divisor of the size of `U`, and
+/// 2. the smallest run of `T`s that is a whole number of `U`s is safely
+///    transmutable into that many `U`s, were it not for alignment.
+///
+/// The length of the destination is computed from the sizes of `T` and
+/// `U`. If the slice is not aligned for `U`, or its size is not a multiple of
+/// the size of `U`, a [`SliceError`] is returned:
+/// ```
+/// use typic::transmute::{SliceError, TransmuteSliceInto};
+///
+/// let words = [0x0102u16, 0x0304];
+/// let bytes = TransmuteSliceInto::<&[u8]>::transmute_slice_into(&words[..]).unwrap();
+/// assert_eq!(bytes.len(), 4);
+///
+/// let pixels = TransmuteSliceInto::<&[[u8; 4]]>::transmute_slice_into(&[0u8; 8][..]).unwrap();
+/// assert_eq!(pixels.len(), 2);
+///
+/// let error = TransmuteSliceInto::<&[[u8; 4]]>::transmute_slice_into(&[0u8; 6][..]).unwrap_err();
+/// assert_eq!(error, SliceError::Size { size: 6, element: 4 });
+/// ```
+///
+/// Element layouts are checked as for references: e.g., a `u8` may not
+/// become a `bool`:
+/// ```compile_fail
+/// # use typic::transmute::TransmuteSliceInto;
+/// let _ = TransmuteSliceInto::<&[bool]>::transmute_slice_into(&[0u8][..]); // Compile Error!
+/// ```
+/// ...and element sizes must divide one another:
+/// ```compile_fail
+/// # use typic::transmute::TransmuteSliceInto;
+/// let _ = TransmuteSliceInto::<&[[u8; 3]]>::transmute_slice_into(&[0u16; 3][..]); // Compile Error!
+/// ```
+pub unsafe trait TransmuteSliceInto<Dst, O = ()>: Sized
+where
+    O: TransmuteOptions,
+{
+    /// Reinterprets the elements of `self` as the elements of `Dst`, if
+    /// `self` is aligned for them and spans a whole number of them.
+    fn transmute_slice_into(self) -> Result<Dst, SliceError>;
+}
+
+/// A ***safe*** and ***sound*** conversion of the longest prefix of a slice
+/// into a slice of another element type, whose remaining elements are
+/// returned alongside it.
+///
+/// It is implemented for the borrowed sources and destinations of
+/// [`TransmuteSliceInto`]:
+/// ```
+/// use typic::transmute::TransmuteSlicePrefixInto;
+///
+/// let bytes = [1u8, 2, 3, 4, 5, 6];
+/// let (chunks, rest) =
+///     TransmuteSlicePrefixInto::<&[[u8; 4]]>::transmute_slice_prefix_into(&bytes[..]).unwrap();
+/// assert_eq!(chunks, &[[1, 2, 3, 4]]);
+/// assert_eq!(rest, &[5, 6]);
+/// ```
+pub unsafe trait TransmuteSlicePrefixInto<Dst, O = ()>: Sized
+where
+    O: TransmuteOptions,
+{
+    /// Reinterprets as many of the elements of `self` as span a whole number
+    /// of the elements of `Dst`, if `self` is aligned for them; the rest are
+    /// returned as they are.
+    fn transmute_slice_prefix_into(self) -> Result<(Dst, Self), SliceError>;
+}
+
+/// The error returned by [`TransmuteSliceInto`] and
+/// [`TransmuteSlicePrefixInto`] when a slice cannot become a slice of the
+/// destination's elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SliceError {
+    /// The address of the slice is not a multiple of `align`, the alignment
+    /// of the destination's elements.
+    Misaligned { address: usize, align: usize },
+    /// The slice's `size`, in bytes, is not a multiple of `element`, the size
+    /// of the destination's elements.
+    Size { size: usize, element: usize },
+}
+
+impl fmt::Display for SliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SliceError::Misaligned { address, align } => write!(
+                f,
+                "the slice at {:#x} is not aligned to {} bytes",
+                address, align
+            ),
+            SliceError::Size { size, element } => write!(
+                f,
+                "a slice of {} bytes is not a whole number of {}-byte elements",
+                size, element
+            ),
+        }
+    }
+}
+
+/// Implemented for `T` if its size is a multiple or a divisor of the size of
+/// `U`, with the number of `T`s and of `U`s in the smallest run of bytes that
+/// is a whole number of both.
+pub trait Chunk<U>: Sized {
+    /// The number of `T`s in a chunk.
+    type Sources: ArrayLength<Self>;
+    /// The number of `U`s in a chunk.
+    type Destinations: ArrayLength<U>;
+}
+
+type SizeOf<T> = <T as Layout>::Size;
+type Smaller<T, U> = Minimum<SizeOf<T>, SizeOf<U>>;
+
+impl<T, U> Chunk<U> for T
+where
+    T: Layout,
+    U: Layout,
+    SizeOf<T>: Min<SizeOf<U>>,
+    SizeOf<T>: Rem<Smaller<T, U>, Output = U0> + Div<Smaller<T, U>>,
+    SizeOf<U>: Rem<Smaller<T, U>, Output = U0> + Div<Smaller<T, U>>,
+    Quot<SizeOf<U>, Smaller<T, U>>: ArrayLength<T>,
+    Quot<SizeOf<T>, Smaller<T, U>>: ArrayLength<U>,
+{
+    type Sources = Quot<SizeOf<U>, Smaller<T, U>>;
+    type Destinations = Quot<SizeOf<T>, Smaller<T, U>>;
+}
+
+/// A chunk of `T`s.
+pub type Sources<T, U> = GenericArray<T, <T as Chunk<U>>::Sources>;
+
+/// A chunk of `U`s.
+pub type Destinations<T, U> = GenericArray<U, <T as Chunk<U>>::Destinations>;
+
+/// Implemented if a pointer to a chunk, `T`, is safely transmutable into a
+/// pointer to a chunk, `Self`, with the options `O`, were it not for
+/// alignment.
+pub trait FromChunk<T, O> {}
+
+impl<T, U, O> FromChunk<T, O> for U
+where
+    O: TransmuteOptions,
+    U: FromType<T, Variant, Unchecked, Enforced, <O as UnsafeTransmuteOptions>::Stability, AlwaysValid>,
+{}
+
+/// The length of the slice of `U`s spanning the `size` bytes at `address`.
+fn length<U>(address: usize, size: usize) -> Result<usize, SliceError> {
+    let align = mem::align_of::<U>();
+    let element = mem::size_of::<U>();
+    if address % align != 0 {
+        Err(SliceError::Misaligned { address, align })
+    } else if size % element != 0 {
+        Err(SliceError::Size { size, element })
+    } else {
+        Ok(size / element)
+    }
+}
+
+/// The number of `T`s at `address` that span the longest whole number of
+/// `U`s, and the number of those `U`s.
+fn prefix<T, U>(address: usize, len: usize) -> Result<(usize, usize), SliceError> {
+    let size = len * mem::size_of::<T>();
+    let size = size - size % mem::size_of::<U>();
+    let len = length::<U>(address, size)?;
+    Ok((size / mem::size_of::<T>(), len))
+}
+
+unsafe impl<'t, 'u, T, U, O> TransmuteSliceInto<&'u [U], O> for &'t [T]
+where
+    O: TransmuteOptions,
+    T: Chunk<U>,
+    &'u Destinations<T, U>: FromChunk<&'t Sources<T, U>, O>,
+{
+    fn transmute_slice_into(self) -> Result<&'u [U], SliceError> {
+        let len = length::<U>(self.as_ptr() as usize, mem::size_of_val(self))?;
+        Ok(unsafe { slice::from_raw_parts(self.as_ptr() as *const U, len) })
+    }
+}
+
+unsafe impl<'t, 'u, T, U, O> TransmuteSliceInto<&'u [U], O> for &'t mut [T]
+where
+    O: TransmuteOptions,
+    T: Chunk<U>,
+    &'u Destinations<T, U>: FromChunk<&'t mut Sources<T, U>, O>,
+{
+    fn transmute_slice_into(self) -> Result<&'u [U], SliceError> {
+        let len = length::<U>(self.as_ptr() as usize, mem::size_of_val(self))?;
+        Ok(unsafe { slice::from_raw_parts(self.as_ptr() as *const U, len) })
+    }
+}
+
+unsafe impl<'t, 'u, T, U, O> TransmuteSliceInto<&'u mut [U], O> for &'t mut [T]
+where
+    O: TransmuteOptions,
+    T: Chunk<U>,
+    &'u mut Destinations<T, U>: FromChunk<&'t mut Sources<T, U>, O>,
+{
+    fn transmute_slice_into(self) -> Result<&'u mut [U], SliceError> {
+        let len = length::<U>(self.as_ptr() as usize, mem::size_of_val(self))?;
+        Ok(unsafe { slice::from_raw_parts_mut(self.as_mut_ptr() as *mut U, len) })
+    }
+}
+
+unsafe impl<'t, 'u, U, O> TransmuteSliceInto<&'u [U], O> for &'t str
+where
+    O: TransmuteOptions,
+    &'t [u8]: TransmuteSliceInto<&'u [U], O>,
+{
+    fn transmute_slice_into(self) -> Result<&'u [U], SliceError> {
+        self.as_bytes().transmute_slice_into()
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod boxed {
+    use super::*;
+    use alloc::boxed::Box;
+
+    unsafe impl<T, U, O> TransmuteSliceInto<Box<[U]>, O> for Box<[T]>
+    where
+        O: TransmuteOptions,
+        T: Chunk<U>,
+        Box<Destinations<T, U>>: FromChunk<Box<Sources<T, U>>, O>,
+    {
+        fn transmute_slice_into(self) -> Result<Box<[U]>, SliceError> {
+            let len = length::<U>(self.as_ptr() as usize, mem::size_of_val(&*self))?;
+            let ptr = Box::into_raw(self) as *mut T as *mut U;
+            // Sound, because `U` has the alignment of `T`, and the slice has
+            // the same size; so it is freed with the layout it was allocated
+            // with.
+            Ok(unsafe { Box::from_raw(slice::from_raw_parts_mut(ptr, len)) })
+        }
+    }
+
+    unsafe impl<'u, T, U, O> TransmuteSliceInto<&'u [U], O> for Box<[T]>
+    where
+        O: TransmuteOptions,
+        T: Chunk<U>,
+        &'u Destinations<T, U>: FromChunk<Box<Sources<T, U>>, O>,
+    {
+        fn transmute_slice_into(self) -> Result<&'u [U], SliceError> {
+            let len = length::<U>(self.as_ptr() as usize, mem::size_of_val(&*self))?;
+            let ptr = Box::leak(self).as_ptr() as *const U;
+            Ok(unsafe { slice::from_raw_parts(ptr, len) })
+        }
+    }
+
+    unsafe impl<'u, T, U, O> TransmuteSliceInto<&'u mut [U], O> for Box<[T]>
+    where
+        O: TransmuteOptions,
+        T: Chunk<U>,
+        &'u mut Destinations<T, U>: FromChunk<Box<Sources<T, U>>, O>,
+    {
+        fn transmute_slice_into(self) -> Result<&'u mut [U], SliceError> {
+            let len = length::<U>(self.as_ptr() as usize, mem::size_of_val(&*self))?;
+            let ptr = Box::leak(self).as_mut_ptr() as *mut U;
+            Ok(unsafe { slice::from_raw_parts_mut(ptr, len) })
+        }
+    }
+}
+
+unsafe impl<'t, 'u, T, U, O> TransmuteSlicePrefixInto<&'u [U], O> for &'t [T]
+where
+    O: TransmuteOptions,
+    T: Chunk<U>,
+    &'u Destinations<T, U>: FromChunk<&'t Sources<T, U>, O>,
+{
+    fn transmute_slice_prefix_into(self) -> Result<(&'u [U], Self), SliceError> {
+        let (split, len) = prefix::<T, U>(self.as_ptr() as usize, self.len())?;
+        let (prefix, rest) = self.split_at(split);
+        Ok((unsafe { slice::from_raw_parts(prefix.as_ptr() as *const U, len) }, rest))
+    }
+}
+
+unsafe impl<'t, 'u, T, U, O> TransmuteSlicePrefixInto<&'u mut [U], O> for &'t mut [T]
+where
+    O: TransmuteOptions,
+    T: Chunk<U>,
+    &'u mut Destinations<T, U>: FromChunk<&'t mut Sources<T, U>, O>,
+{
+    fn transmute_slice_prefix_into(self) -> Result<(&'u mut [U], Self), SliceError> {
+        let (split, len) = prefix::<T, U>(self.as_ptr() as usize, self.len())?;
+        let (prefix, rest) = self.split_at_mut(split);
+        Ok((unsafe { slice::from_raw_parts_mut(prefix.as_mut_ptr() as *mut U, len) }, rest))
+    }
+}
@@ -61,6 +61,14 @@ pub use crate::private::transmute::{
     UnsafeTransmuteFrom,
     UnsafeTransmuteInto,
     neglect::UnsafeTransmuteOptions,
+
+    TryTransmuteFrom,
+    TryTransmuteInto,
+    ValidityError,
+
+    slice::{SliceError, TransmuteSliceInto, TransmuteSlicePrefixInto},
+
+    explain::{Divergence, Explanation, Location, Reason},
 };
 
 /// What static checks should Typic neglect?
@@ -148,6 +148,37 @@
 //! // This violates the safety invariant of `something_dangerous`!
 //! x.something_dangerous();
 //! ```
+//!
+//! ## Safely reading unions
+//! A union may be initialized through any of its fields, so each byte of a
+//! union is only as restricted as the least restricted corresponding byte of
+//! its fields. If every other field's bytes are valid for a particular field,
+//! that field may be read by safely transmuting the union:
+//! ```
+//! # use typic::docs::prelude::*;
+//! #[typic::repr(C)]
+//! #[derive(Clone, Copy, StableABI)]
+//! union Word {
+//!     pub int: u32,
+//!     pub bytes: [u8; 4],
+//! }
+//!
+//! let int: u32 = Word { bytes: [0xFF; 4] }.transmute_into();
+//! let bytes: &[u8; 4] = (&Word { int: 42 }).transmute_into();
+//! ```
+//! ...but not if another field may leave those bytes invalid:
+//! ```compile_fail
+//! # use typic::docs::prelude::*;
+//! #[typic::repr(C)]
+//! #[derive(Clone, Copy, StableABI)]
+//! union Maybe {
+//!     pub zero: u32,
+//!     pub nonzero: core::num::NonZeroU32,
+//! }
+//!
+//! let _: core::num::NonZeroU32 = Maybe { zero: 0 }.transmute_into(); // Compile Error!
+//! ```
+//! A union is only safely transmutable _into_ if all of its fields are `pub`.
 
 pub use super::{
     safe_transmute,
@@ -114,7 +114,8 @@
 //!  - [preserve or relax alignment][reference-alignment],
 //!  - [preserve or shrink lifetimes][reference-lifetimes],
 //!  - [preserve or shrink mutability][reference-mutability], and
-//!  - [preserve validity][reference-validity].
+//!  - [preserve validity][reference-validity] (or, for shared references,
+//!    relax it).
 //!
 //! #### Preserve or Relax Alignment
 //! [reference-alignment]: #preserve-or-relax-alignment
@@ -175,8 +176,9 @@
 //! #### Preserve Validity
 //! [reference-validity]: #preserve-validity
 //!
-//! Unlike transmutations of owned values, the transmutation of a reference may
-//! also not expand the bit-validity of the referenced type. For instance:
+//! Unlike transmutations of owned values, the transmutation of a unique
+//! reference may also not expand the bit-validity of the referenced type. For
+//! instance:
 //!
 //! ```compile_fail
 //! # use typic::docs::prelude::*;
@@ -190,6 +192,25 @@
 //! ```
 //! If this example did not produce a compile error, the value of `z` would not
 //! be a bit-valid instance of its type.
+//!
+//! A shared reference may only be read through, so it *may* expand the
+//! bit-validity of the referenced type:
+//! ```rust
+//! # use typic::docs::prelude::*;
+//! let x = NonZeroU8::new(42).unwrap();
+//! let _: &u8 = (&x).transmute_into();
+//! ```
+//! ...except that of bytes which may be mutated through it (i.e., the bytes of
+//! a `Cell` or `UnsafeCell`), whose validity and interior mutability must be
+//! preserved:
+//! ```compile_fail
+//! # use typic::docs::prelude::*;
+//! use core::cell::Cell;
+//!
+//! let x = Cell::new(NonZeroU8::new(42).unwrap());
+//! let y: &Cell<u8> = (&x).transmute_into(); // Compile Error!
+//! y.set(0);
+//! ```
 
 #[doc(inline)]
 pub use crate::transmute::{
@@ -1,4 +1,4 @@
-#![feature(concat_idents)]
+#![allow(non_camel_case_types, clippy::repr_packed_without_abi)]
 
 use core::mem::align_of;
 use static_assertions::*;
@@ -31,8 +31,9 @@ mod zst {
         MaxAlign,
         <ZST_C as Type>::ReprPacked,
         <ZST_Rust as Type>::ReprPacked,
-        <ZST_Packed as Type>::ReprPacked,
     ];
+
+    assert_type_eq_all![U1, <ZST_Packed as Type>::ReprPacked];
 }
 
 mod align_1 {
@@ -65,3 +66,82 @@ mod multi_align {
         <Align4_2 as Type>::ReprAlign,
     ];
 }
+
+mod packed {
+    use super::*;
+    use core::mem::size_of;
+    use typenum::Unsigned;
+    use typic::layout::{AlignOf, SizeOf};
+
+    #[typic::repr(C, packed)]
+    struct Packed1(u8, u32);
+
+    #[typic::repr(C, packed(2))]
+    struct Packed2(u8, u32);
+
+    #[typic::repr(C)]
+    #[repr(packed(4))]
+    struct Packed4(u8, u64);
+
+    assert_type_eq_all!(<Packed1 as Type>::ReprPacked, U1);
+    assert_type_eq_all!(<Packed2 as Type>::ReprPacked, U2);
+    assert_type_eq_all!(<Packed4 as Type>::ReprPacked, U4);
+
+    #[test]
+    fn layouts() {
+        assert_eq!(SizeOf::<Packed1>::USIZE, size_of::<Packed1>());
+        assert_eq!(SizeOf::<Packed2>::USIZE, size_of::<Packed2>());
+        assert_eq!(SizeOf::<Packed4>::USIZE, size_of::<Packed4>());
+        assert_eq!(AlignOf::<Packed1>::USIZE, align_of::<Packed1>());
+        assert_eq!(AlignOf::<Packed2>::USIZE, align_of::<Packed2>());
+        assert_eq!(AlignOf::<Packed4>::USIZE, align_of::<Packed4>());
+    }
+}
+
+mod wide_enums {
+    use super::*;
+    use core::mem::size_of;
+    use typenum::Unsigned;
+    use typic::layout::SizeOf;
+
+    #[typic::repr(i128)]
+    #[allow(dead_code)]
+    enum Signed { A = -1, B = 1 }
+
+    #[typic::repr(u128)]
+    #[allow(dead_code)]
+    enum Unsigned128 { A, B }
+
+    #[test]
+    fn layouts() {
+        assert_eq!(SizeOf::<Signed>::USIZE, size_of::<Signed>());
+        assert_eq!(SizeOf::<Unsigned128>::USIZE, size_of::<Unsigned128>());
+    }
+}
+
+mod layout_check {
+    use core::time::Duration;
+
+    // `Duration` has no typic layout, so the layout of `Unchecked` cannot be
+    // checked.
+    #[typic::repr(C)]
+    #[typic(no_layout_check)]
+    #[allow(dead_code)]
+    struct Unchecked(u8, Duration);
+
+    #[typic::repr(C)]
+    #[allow(dead_code)]
+    struct Borrowed<'a> {
+        a: u8,
+        b: &'a u64,
+        c: u16,
+    }
+
+    #[typic::repr(C)]
+    #[allow(dead_code)]
+    struct WithUnit(pub u8, pub ());
+
+    #[typic::repr(C)]
+    #[allow(dead_code)]
+    struct LeadingUnit((), u16, ());
+}
@@ -0,0 +1,135 @@
+use typic::layout::{describe, Description, Kind, Segment, Value, Visibility::*};
+
+#[test]
+fn describe_products() {
+    #[typic::repr(C)]
+    struct Padded(pub u8, u16, pub u8);
+
+    const PADDED: Description = describe::<Padded>();
+    const _: () = assert!(PADDED.size() == 6 && PADDED.align() == 2);
+    const _: () = assert!(PADDED.segments().len() == 5);
+
+    let segments: Vec<_> = PADDED.segments().collect();
+    assert_eq!(segments, [
+        Segment { offset: 0, size: 1, kind: Kind::Initialized, visibility: Public, interior: false },
+        Segment { offset: 1, size: 1, kind: Kind::Padding, visibility: Public, interior: false },
+        Segment { offset: 2, size: 2, kind: Kind::Initialized, visibility: Private, interior: false },
+        Segment { offset: 4, size: 1, kind: Kind::Initialized, visibility: Public, interior: false },
+        Segment { offset: 5, size: 1, kind: Kind::Padding, visibility: Public, interior: false },
+    ]);
+
+    #[typic::repr(C)]
+    struct Empty;
+
+    assert!(describe::<Empty>().segments().is_empty());
+}
+
+#[test]
+fn describe_arrays_and_references() {
+    #[typic::repr(C)]
+    struct Refs<'a>(pub &'a u8, pub &'a mut u8, pub [u16; 3]);
+
+    let description = describe::<Refs<'static>>();
+    let width = core::mem::size_of::<usize>();
+    let kinds: Vec<_> = description.segments().map(|s| (s.offset, s.kind)).collect();
+    match kinds[..2] {
+        [(0, Kind::Reference { unique: false, owned: false, referent: a }),
+         (offset, Kind::Reference { unique: true, owned: false, referent: b })] => {
+            assert_eq!(offset, width);
+            assert_eq!(*a, describe::<u8>());
+            assert_eq!(*b, describe::<u8>());
+        }
+        ref other => panic!("unexpected segments: {:?}", other),
+    }
+
+    match kinds[2] {
+        (offset, Kind::Array { element, count: 3 }) => {
+            assert_eq!(offset, 2 * width);
+            assert_eq!(element.size(), 2);
+            assert_eq!(element.segments().len(), 1);
+        }
+        other => panic!("unexpected segment: {:?}", other),
+    }
+}
+
+#[test]
+fn describe_enums() {
+    #[typic::repr(u8)]
+    #[allow(dead_code)]
+    enum Enum {
+        A,
+        B(u8),
+    }
+
+    let description = describe::<Enum>();
+    assert_eq!(description.size(), 2);
+
+    let variants = match description.segments().get(0).map(|s| s.kind) {
+        Some(Kind::Variants(variants)) => variants,
+        other => panic!("unexpected segment: {:?}", other),
+    };
+    assert_eq!(variants.len(), 2);
+
+    let a = variants.get(0).unwrap();
+    assert_eq!(a.discriminant, 0);
+    assert_eq!(a.segments.map(|s| s.kind).collect::<Vec<_>>(), [Kind::Value(Value::Unsigned(0)), Kind::Padding]);
+
+    let b = variants.get(1).unwrap();
+    assert_eq!(b.discriminant, 1);
+    assert_eq!(b.segments.map(|s| s.kind).collect::<Vec<_>>(), [Kind::Value(Value::Unsigned(1)), Kind::Initialized]);
+
+    // Signed discriminants keep their sign, but are matched by their bits:
+    #[typic::repr(i16)]
+    #[allow(dead_code)]
+    enum Signed {
+        N = -2,
+    }
+
+    let variant = match describe::<Signed>().segments().get(0).map(|s| s.kind) {
+        Some(Kind::Variants(mut variants)) => variants.next().unwrap(),
+        other => panic!("unexpected segment: {:?}", other),
+    };
+    assert_eq!(variant.discriminant, 0xFFFE);
+    assert_eq!(variant.segments.get(0).map(|s| s.kind), Some(Kind::Value(Value::Signed(-2))));
+    assert_eq!(Value::Signed(-2).bits(2), 0xFFFE);
+    assert_eq!(Value::Signed(-2).to_string(), "-2");
+}
+
+#[test]
+fn describe_bool_and_char() {
+    let kinds = |description: Description| -> Vec<_> {
+        description.segments().map(|s| (s.offset, s.size, s.kind)).collect()
+    };
+    assert_eq!(kinds(describe::<bool>()), [(0, 1, Kind::Range { start: 0, end: 1 })]);
+    assert_eq!(kinds(describe::<char>()), [(0, 4, Kind::Char)]);
+}
+
+#[test]
+fn describe_ranges() {
+    use core::num::{NonZeroU128, NonZeroU16};
+    use typenum::{U10, U20};
+    use typic::layout::Ranged;
+
+    let kind = |description: Description| description.segments().get(0).map(|s| (s.size, s.kind));
+    assert_eq!(kind(describe::<NonZeroU16>()), Some((2, Kind::Range { start: 1, end: 0xFFFF })));
+    assert_eq!(
+        kind(describe::<NonZeroU128>()),
+        Some((16, Kind::Range { start: 1, end: u128::MAX }))
+    );
+    assert_eq!(
+        kind(describe::<Ranged<u32, U10, U20>>()),
+        Some((4, Kind::Range { start: 10, end: 20 }))
+    );
+    assert_eq!(kind(describe::<Ranged<u8, U10, U10>>()), Some((1, Kind::Value(Value::Unsigned(10)))));
+}
+
+#[test]
+fn describe_opaques() {
+    type Borrowing<'a> = fn(&'a u8) -> &'a u8;
+
+    // Opaque types are identified by their `TypeId`, not their name.
+    assert_eq!(describe::<fn()>(), describe::<fn()>());
+    assert_eq!(describe::<Borrowing<'static>>(), describe::<Borrowing<'_>>());
+    assert_ne!(describe::<fn()>(), describe::<fn(u8)>());
+    assert_ne!(describe::<fn(u8)>(), describe::<fn(i8)>());
+}
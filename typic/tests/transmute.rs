@@ -1,5 +1,4 @@
-use core::mem::align_of;
-use core::num::NonZeroU8;
+use core::num::{NonZeroU8, NonZeroUsize};
 use static_assertions::*;
 use typic::{self, transmute::StableTransmuteInto, stability::StableABI};
 
@@ -82,9 +81,23 @@ fn references() {
     // ...but not a more strictly aligned type:
     assert_not_impl_any!(&'static [u8; 0]: StableTransmuteInto<&'static [u16; 0]>);
 
-    // You cannot alter the validity with a pointer transmute:
+    // A shared referent may only be read, so its validity may expand:
+    let x = NonZeroU8::new(1).unwrap();
+    let _: &u8 = (&x).transmute_into();
+    let _: &[u8; 2] = (&[x; 2]).transmute_into();
+    let _: &&u8 = (&&x).transmute_into();
+    let _: &u8 = (&mut NonZeroU8::new(1).unwrap()).transmute_into();
+
+    // ...but not narrow:
     assert_not_impl_any!(&'static u8: StableTransmuteInto<&'static NonZeroU8>);
-    assert_not_impl_any!(&'static NonZeroU8: StableTransmuteInto<&'static u8>);
+
+    // A unique referent may be written, so its validity may not change:
+    assert_not_impl_any!(&'static mut u8: StableTransmuteInto<&'static mut NonZeroU8>);
+    assert_not_impl_any!(&'static mut NonZeroU8: StableTransmuteInto<&'static mut u8>);
+
+    // ...nor may that of the referent of a reference that may be overwritten:
+    assert_not_impl_any!(&'static mut &'static NonZeroU8: StableTransmuteInto<&'static mut &'static u8>);
+    let _: &&u8 = (&mut &x).transmute_into();
 
     // You may decrease the size:
     let _: &u8 = (&0u16).transmute_into();
@@ -103,4 +116,1310 @@ fn references() {
 
     assert_not_impl_any!(&'static A: StableTransmuteInto<&'static B>);
     assert_not_impl_any!(&'static B: StableTransmuteInto<&'static A>);
+    assert_not_impl_any!(&'static mut A: StableTransmuteInto<&'static mut B>);
+    assert_not_impl_any!(&'static mut A: StableTransmuteInto<&'static B>);
+}
+
+#[test]
+fn fieldless_enums() {
+    #[typic::repr(u8)]
+    #[derive(StableABI)]
+    enum Small {
+        A = 1,
+        B,
+    }
+
+    #[typic::repr(u8)]
+    #[derive(StableABI)]
+    #[allow(dead_code)]
+    enum Wide {
+        Z = 0,
+        A,
+        B,
+        C = 255,
+    }
+
+    #[typic::repr(i32)]
+    #[derive(StableABI)]
+    #[allow(dead_code)]
+    enum Signed {
+        N = -1,
+        P = 1,
+    }
+
+    #[typic::repr(u32)]
+    #[derive(StableABI)]
+    #[allow(dead_code)]
+    enum Unsigned {
+        N = 0xFFFF_FFFF,
+        P = 1,
+    }
+
+    #[typic::repr(C)]
+    #[derive(StableABI)]
+    enum C {
+        A = 1,
+    }
+
+    #[typic::repr(isize)]
+    #[derive(StableABI)]
+    enum Pointer {
+        N = -1,
+    }
+
+    // Every discriminant is a valid `u8`:
+    let x: u8 = Small::B.transmute_into();
+    assert_eq!(x, 2);
+    let x: u8 = Wide::C.transmute_into();
+    assert_eq!(x, 255);
+    let x: u32 = Signed::N.transmute_into();
+    assert_eq!(x, u32::MAX);
+    let x: i32 = C::A.transmute_into();
+    assert_eq!(x, 1);
+    let x: usize = Pointer::N.transmute_into();
+    assert_eq!(x, usize::MAX);
+
+    // ...but not every `u8` is a valid discriminant:
+    assert_not_impl_any!(u8: StableTransmuteInto<Small>);
+    assert_not_impl_any!(u32: StableTransmuteInto<Signed>);
+    assert_not_impl_any!(i32: StableTransmuteInto<C>);
+
+    // An enum may be transmuted into another with a superset of its
+    // discriminants, but not a subset:
+    let _: Wide = Small::A.transmute_into();
+    assert_not_impl_any!(Wide: StableTransmuteInto<Small>);
+    assert_not_impl_any!(Signed: StableTransmuteInto<C>);
+
+    // Discriminants are matched by their bit patterns, whatever their
+    // signedness:
+    let _: Unsigned = Signed::N.transmute_into();
+    let _: Signed = Unsigned::P.transmute_into();
+
+    // The sizes of the discriminants must match:
+    assert_not_impl_any!(Signed: StableTransmuteInto<Small>);
+    assert_not_impl_any!(Small: StableTransmuteInto<Signed>);
+
+    // Enums may be embedded in other types:
+    #[typic::repr(C)]
+    #[derive(StableABI)]
+    struct Pair(pub Small, pub u8);
+
+    #[typic::repr(C)]
+    #[derive(StableABI)]
+    struct WidePair(pub Wide, pub u8);
+
+    let _: WidePair = Pair(Small::B, 7).transmute_into();
+    let _: [u8; 2] = Pair(Small::B, 7).transmute_into();
+    let _: [Wide; 2] = [Small::A, Small::B].transmute_into();
+    assert_not_impl_any!([u8; 2]: StableTransmuteInto<Pair>);
+    assert_not_impl_any!(WidePair: StableTransmuteInto<Pair>);
+}
+
+#[test]
+fn data_enums() {
+    use core::mem::{align_of, size_of};
+    use typenum::Unsigned;
+    use typic::layout::{AlignOf, Layout, SizeOf};
+
+    fn assert_layout<T: Layout>() {
+        assert_eq!(size_of::<T>(), SizeOf::<T>::USIZE);
+        assert_eq!(align_of::<T>(), AlignOf::<T>::USIZE);
+    }
+
+    #[typic::repr(u8)]
+    #[derive(StableABI)]
+    #[allow(dead_code)]
+    enum Message {
+        Ping = 1,
+        Data(u8, u32),
+        Pair { a: u16, b: u16 },
+    }
+
+    #[typic::repr(u8)]
+    #[derive(StableABI)]
+    #[allow(dead_code)]
+    enum Mirror {
+        Ping = 1,
+        Data(u8, u32),
+        Pair { a: u16, b: u16 },
+    }
+
+    #[typic::repr(C)]
+    #[derive(StableABI)]
+    #[allow(dead_code)]
+    enum CMessage {
+        A(u8),
+        B(u64),
+    }
+
+    #[typic::repr(C, u8)]
+    #[derive(StableABI)]
+    #[allow(dead_code)]
+    enum CU8Message {
+        A(u8),
+        B(u64),
+    }
+
+    #[typic::repr(u16)]
+    #[allow(dead_code)]
+    enum Generic<T> {
+        A(T),
+        B,
+    }
+
+    assert_layout::<Message>();
+    assert_layout::<CMessage>();
+    assert_layout::<CU8Message>();
+    assert_layout::<Generic<u64>>();
+
+    // Enums sharing a wire layout are mutually transmutable:
+    let _: Mirror = Message::Data(1, 2).transmute_into();
+    let _: Message = Mirror::Pair { a: 1, b: 2 }.transmute_into();
+
+    // Padding in any variant may not become initialized bytes:
+    assert_not_impl_any!(Message: StableTransmuteInto<[u8; 8]>);
+
+    #[typic::repr(u8)]
+    #[derive(StableABI)]
+    #[allow(dead_code)]
+    enum Narrow {
+        A(u16) = 1,
+    }
+
+    #[typic::repr(u8)]
+    #[derive(StableABI)]
+    #[allow(dead_code)]
+    enum Broad {
+        A(u16) = 1,
+        B(u8, u8),
+    }
+
+    #[typic::repr(u8)]
+    #[derive(StableABI)]
+    #[allow(dead_code)]
+    enum Restricted {
+        A(core::num::NonZeroU16) = 1,
+    }
+
+    // Every variant of the source must be a variant of the destination:
+    let _: Broad = Narrow::A(7).transmute_into();
+    assert_not_impl_any!(Broad: StableTransmuteInto<Narrow>);
+
+    // ...whose fields are transmutable:
+    let _: Narrow = Restricted::A(core::num::NonZeroU16::new(7).unwrap()).transmute_into();
+    assert_not_impl_any!(Narrow: StableTransmuteInto<Restricted>);
+
+    // An enum may be transmuted into a struct that covers every variant:
+    #[typic::repr(u8)]
+    #[derive(StableABI)]
+    #[allow(dead_code)]
+    enum Byte {
+        Unsigned(u8),
+        Signed(i8),
+    }
+
+    #[typic::repr(C)]
+    #[derive(StableABI)]
+    struct Raw(pub u8, pub u8);
+
+    let Raw(tag, value) = Byte::Signed(-1).transmute_into();
+    assert_eq!((tag, value), (1, 255));
+    assert_not_impl_any!(Raw: StableTransmuteInto<Byte>);
+}
+
+#[test]
+fn unions() {
+    use core::mem::{align_of, size_of};
+    use core::num::NonZeroU32;
+    use typenum::Unsigned;
+    use typic::layout::{AlignOf, SizeOf};
+
+    #[typic::repr(C)]
+    #[derive(Clone, Copy, StableABI)]
+    union Word {
+        pub int: u32,
+        pub bytes: [u8; 4],
+    }
+
+    #[typic::repr(C)]
+    #[derive(Clone, Copy, StableABI)]
+    union Partial {
+        pub byte: u8,
+        pub int: u32,
+    }
+
+    #[typic::repr(C)]
+    #[derive(Clone, Copy, StableABI)]
+    union Restricted {
+        pub zero: u32,
+        pub nonzero: NonZeroU32,
+    }
+
+    #[typic::repr(C)]
+    #[derive(Clone, Copy, StableABI)]
+    #[allow(dead_code)]
+    union Secret {
+        pub int: u32,
+        bytes: [u8; 4],
+    }
+
+    assert_eq!(size_of::<Partial>(), SizeOf::<Partial>::USIZE);
+    assert_eq!(align_of::<Partial>(), AlignOf::<Partial>::USIZE);
+
+    // A field may be safely read from a union if every other field's bytes
+    // are valid for it:
+    let x: u32 = Word { bytes: [1, 0, 0, 0] }.transmute_into();
+    assert_eq!(x, u32::from_ne_bytes([1, 0, 0, 0]));
+    let _: [u8; 4] = Word { int: 7 }.transmute_into();
+    let _: &u32 = (&Word { int: 7 }).transmute_into();
+    let _: u32 = Restricted { zero: 0 }.transmute_into();
+    let _: u8 = Partial { byte: 7 }.transmute_into();
+
+    // ...but not otherwise:
+    assert_not_impl_any!(Restricted: StableTransmuteInto<NonZeroU32>);
+    assert_not_impl_any!(Partial: StableTransmuteInto<u32>);
+
+    // A value may be transmuted into a union if it fits any one field:
+    let _: Word = 7u32.transmute_into();
+    let _: Restricted = 7u32.transmute_into();
+    let _: Partial = 7u32.transmute_into();
+    let _: Word = NonZeroU32::new(7).unwrap().transmute_into();
+    assert_not_impl_any!(u16: StableTransmuteInto<Word>);
+
+    // A union with private fields may not be transmuted into:
+    let _: u32 = Secret { int: 7 }.transmute_into();
+    assert_not_impl_any!(u32: StableTransmuteInto<Secret>);
+}
+
+#[test]
+fn transparent() {
+    use core::marker::PhantomData;
+    use core::mem::{align_of, size_of};
+    use typenum::Unsigned;
+    use typic::layout::{AlignOf, SizeOf};
+
+    #[typic::repr(transparent)]
+    #[derive(Clone, Copy, StableABI)]
+    pub struct Id(pub u32);
+
+    #[typic::repr(transparent)]
+    #[derive(StableABI)]
+    pub struct Handle<T>(pub u64, PhantomData<T>);
+
+    #[typic::repr(transparent)]
+    #[derive(StableABI)]
+    pub struct Wrapper(pub Id);
+
+    #[typic::repr(transparent)]
+    #[derive(StableABI)]
+    pub struct Opaque(u32);
+
+    #[typic::repr(transparent)]
+    #[derive(StableABI)]
+    pub struct Marker(PhantomData<u64>);
+
+    assert_eq!(SizeOf::<Id>::USIZE, size_of::<Id>());
+    assert_eq!(AlignOf::<Id>::USIZE, align_of::<Id>());
+    assert_eq!(SizeOf::<Handle<u8>>::USIZE, size_of::<Handle<u8>>());
+    assert_eq!(AlignOf::<Handle<u8>>::USIZE, align_of::<Handle<u8>>());
+    assert_eq!(SizeOf::<Marker>::USIZE, 0);
+
+    let id: Id = 42u32.transmute_into();
+    let raw: u32 = id.transmute_into();
+    assert_eq!(raw, 42);
+
+    let _: Handle<String> = 7u64.transmute_into();
+    let _: [u8; 4] = Wrapper(id).transmute_into();
+    let _: &u32 = (&Wrapper(id)).transmute_into();
+    let _: Marker = ().transmute_into();
+
+    // The visibility of the inner field is preserved:
+    let _: u32 = Opaque(42).transmute_into();
+    assert_not_impl_any!(u32: StableTransmuteInto<Opaque>);
+
+    // Any 1-aligned ZST may accompany the inner field, however it is named:
+    use core::marker::PhantomData as Marked;
+
+    #[typic::repr(transparent)]
+    #[derive(Clone, Copy, StableABI)]
+    pub struct Tag(());
+
+    #[typic::repr(transparent)]
+    #[derive(StableABI)]
+    pub struct Tagged(pub Tag, pub u16, pub [u8; 0], pub Marked<u64>);
+
+    assert_eq!(SizeOf::<Tagged>::USIZE, size_of::<Tagged>());
+    assert_eq!(AlignOf::<Tagged>::USIZE, align_of::<Tagged>());
+    let _: Tagged = 7u16.transmute_into();
+    let _: [u8; 2] = Tagged(Tag(()), 7, [], Marked).transmute_into();
+}
+
+#[test]
+fn opaque() {
+    use core::mem::{align_of, size_of};
+    use typenum::Unsigned;
+    use typic::layout::{AlignOf, SizeOf};
+    use typic::transmute::{neglect, TransmuteInto};
+
+    #[typic::repr()]
+    #[derive(Default)]
+    #[allow(dead_code)]
+    pub struct Foo {
+        pub a: u8,
+        pub b: u32,
+    }
+
+    #[typic::repr()]
+    #[derive(Default)]
+    #[allow(dead_code)]
+    pub struct Bar {
+        pub a: u8,
+        pub b: u32,
+    }
+
+    #[typic::repr(C)]
+    #[derive(Default)]
+    pub struct Holder(pub u64, pub Foo);
+
+    assert_eq!(SizeOf::<Foo>::USIZE, size_of::<Foo>());
+    assert_eq!(AlignOf::<Foo>::USIZE, align_of::<Foo>());
+    assert_eq!(SizeOf::<Holder>::USIZE, size_of::<Holder>());
+
+    // Identity transmutations are permitted, if stability is neglected:
+    let foo = Foo::default();
+    let _: &Foo = TransmuteInto::<_, neglect::Stability>::transmute_into(&foo);
+    let _: Foo = TransmuteInto::<_, neglect::Stability>::transmute_into(Foo::default());
+    let _: Holder = TransmuteInto::<_, neglect::Stability>::transmute_into(Holder::default());
+
+    // ...but not otherwise:
+    assert_not_impl_any!(Foo: TransmuteInto<Foo>);
+
+    // Nor is any reinterpretation:
+    assert_not_impl_any!(Foo: TransmuteInto<Bar, neglect::Stability>);
+    assert_not_impl_any!(Foo: TransmuteInto<[u8; 8], neglect::Stability>);
+    assert_not_impl_any!([u8; 8]: TransmuteInto<Foo, neglect::Stability>);
+    assert_not_impl_any!(&'static Foo: TransmuteInto<&'static Bar, neglect::Stability>);
+    assert_not_impl_any!(Holder: TransmuteInto<[u64; 2], neglect::Stability>);
+
+    // The size of a generic type cannot be computed, so it has no layout:
+    #[typic::repr(Rust)]
+    #[derive(Default)]
+    #[allow(dead_code)]
+    pub struct Generic<T>(pub T);
+
+    assert_not_impl_any!(Generic<u8>: typic::layout::Layout);
+    assert_not_impl_any!(Generic<u8>: TransmuteInto<Generic<u8>, neglect::Stability>);
+}
+
+#[test]
+fn abi_bounds() {
+    use typic::stability::{TransmutableFrom, TransmutableInto};
+
+    // `Header` promises only that it begins with two initialized bytes, and
+    // that it may grow to as many as four.
+    #[typic::repr(C)]
+    #[derive(Default, StableABI)]
+    #[stable_abi(into = "[u8; 2]", from = "[u8; 4]")]
+    pub struct Header {
+        pub tag: u8,
+        pub len: u8,
+    }
+
+    #[typic::repr(C)]
+    #[derive(Default, StableABI)]
+    #[stable_abi(into = "u8")]
+    pub struct Generic<T> {
+        pub tag: u8,
+        pub rest: T,
+    }
+
+    assert_type_eq_all!(<Header as TransmutableInto>::Type, [u8; 2]);
+    assert_type_eq_all!(<Header as TransmutableFrom>::Type, [u8; 4]);
+    assert_type_eq_all!(<Generic<u8> as TransmutableInto>::Type, u8);
+    assert_type_eq_all!(<Generic<u8> as TransmutableFrom>::Type, Generic<u8>);
+
+    // A type may be transmuted into what its `into` bound promises...
+    let _: [u8; 2] = Header::default().transmute_into();
+    let _: u8 = Header::default().transmute_into();
+    let _: u8 = Generic::<u8>::default().transmute_into();
+
+    // ...but not beyond it:
+    assert_not_impl_any!(Header: StableTransmuteInto<[u8; 4]>);
+    assert_not_impl_any!(Generic<u8>: StableTransmuteInto<[u8; 2]>);
+
+    // A type may be transmuted from what its `from` bound promises...
+    let _: Header = [0u8; 4].transmute_into();
+    let _: Header = [0u32; 2].transmute_into();
+
+    // ...but not from less:
+    assert_not_impl_any!([u8; 2]: StableTransmuteInto<Header>);
+    assert_not_impl_any!(Generic<u8>: StableTransmuteInto<Header>);
+}
+
+#[test]
+fn explain() {
+    use typic::explain;
+    use typic::layout::Kind;
+    use typic::transmute::{neglect, Location, Reason};
+
+    #[typic::repr(C)]
+    #[derive(StableABI)]
+    pub struct Padded(pub u8, pub u16);
+
+    #[typic::repr(C)]
+    #[derive(StableABI)]
+    pub struct Private(u8, u16);
+
+    #[typic::repr(u8)]
+    #[derive(StableABI)]
+    #[allow(dead_code)]
+    enum Small {
+        A = 1,
+        B,
+    }
+
+    #[typic::repr(C)]
+    #[derive(StableABI)]
+    #[stable_abi(into = "u8", from = "[u8; 4]")]
+    pub struct Tagged {
+        pub tag: u8,
+        pub len: u8,
+    }
+
+    fn reason<T, U>() -> Option<(usize, Reason, Location)>
+    where
+        T: typic::layout::Describe + typic::stability::TransmutableInto,
+        U: typic::layout::Describe + typic::stability::TransmutableFrom,
+        <T as typic::stability::TransmutableInto>::Type: typic::layout::Describe,
+        <U as typic::stability::TransmutableFrom>::Type: typic::layout::Describe,
+    {
+        explain::<T, U, ()>()
+            .divergence()
+            .map(|d| (d.offset, d.reason, d.location))
+    }
+
+    assert_eq!(reason::<u32, Padded>(), None);
+    assert_eq!(reason::<Private, Padded>(), None);
+    assert_eq!(reason::<Small, u8>(), None);
+    assert_eq!(reason::<&'static u16, &'static u8>(), None);
+
+    assert_eq!(reason::<Padded, u32>(), Some((1, Reason::Padding, Location::Value)));
+    assert_eq!(reason::<u16, u32>(), Some((2, Reason::Size, Location::Value)));
+    assert_eq!(reason::<u8, NonZeroU8>(), Some((0, Reason::Validity, Location::Value)));
+    assert_eq!(reason::<u8, Small>(), Some((0, Reason::Validity, Location::Value)));
+    assert_eq!(reason::<u16, u8>(), None);
+    assert_eq!(
+        reason::<&'static [u8; 2], &'static u16>(),
+        Some((0, Reason::Alignment, Location::Value))
+    );
+    assert_eq!(
+        reason::<&'static u8, &'static mut u8>(),
+        Some((0, Reason::Mutability, Location::Value))
+    );
+    assert_eq!(
+        reason::<&'static mut NonZeroU8, &'static mut u8>(),
+        Some((0, Reason::Invariance, Location::Referent))
+    );
+    assert_eq!(
+        reason::<&'static mut &'static NonZeroU8, &'static mut &'static u8>(),
+        Some((0, Reason::Invariance, Location::Referent))
+    );
+    assert_eq!(reason::<&'static NonZeroU8, &'static u8>(), None);
+    assert_eq!(
+        reason::<&'static u8, &'static NonZeroU8>(),
+        Some((0, Reason::Validity, Location::Referent))
+    );
+
+    // A layout-compatible transmutation may still exceed the ABI bounds:
+    assert_eq!(reason::<Tagged, u8>(), None);
+    assert_eq!(reason::<Tagged, [u8; 2]>(), Some((1, Reason::Size, Location::AbiBound)));
+    assert_eq!(reason::<[u8; 2], Tagged>(), Some((2, Reason::Size, Location::AbiBound)));
+    assert!(explain::<Tagged, [u8; 2], neglect::Stability>().is_transmutable());
+    assert_not_impl_any!(Tagged: StableTransmuteInto<[u8; 2]>);
+    assert_not_impl_any!([u8; 2]: StableTransmuteInto<Tagged>);
+
+    // Private fields may only be written with `neglect::Transparency`:
+    let explanation = explain::<u32, Private, ()>();
+    assert_eq!(explanation.divergence().map(|d| d.reason), Some(Reason::IntoPrivate));
+    assert!(explain::<u32, Private, neglect::Transparency>().is_transmutable());
+
+    // The explanation agrees with the static checks:
+    let _: Padded = 0u32.transmute_into();
+    let _: Padded = Private(0, 0).transmute_into();
+    let _: u8 = Small::A.transmute_into();
+    assert_not_impl_any!(Padded: StableTransmuteInto<u32>);
+    assert_not_impl_any!(u8: StableTransmuteInto<Small>);
+    assert_not_impl_any!(Padded: StableTransmuteInto<Private>);
+
+    let divergence = explain::<Padded, u32, ()>().divergence().unwrap();
+    assert_eq!(divergence.source, Some(Kind::Padding));
+    assert_eq!(divergence.destination, Kind::Initialized);
+    assert_eq!(
+        explain::<u8, NonZeroU8, ()>().to_string(),
+        "`u8` is not transmutable into `core::num::nonzero::NonZero<u8>`: at offset 0, \
+         initialized bytes may not be valid as a value in 1..=255",
+    );
+    assert_eq!(
+        explain::<u32, [u8; 4], ()>().to_string(),
+        "`u32` is transmutable into `[u8; 4]`",
+    );
+}
+
+#[test]
+fn try_transmute() {
+    use typic::layout::{Kind, Value};
+    use typic::transmute::TryTransmuteInto;
+
+    #[typic::repr(u8)]
+    #[derive(Debug, PartialEq, StableABI)]
+    pub enum Small {
+        A = 1,
+        B,
+    }
+
+    #[typic::repr(i16)]
+    #[derive(Debug, PartialEq, StableABI)]
+    pub enum Negative {
+        Two = -2,
+    }
+
+    #[typic::repr(C)]
+    #[derive(Debug, PartialEq, StableABI)]
+    pub struct Pair(pub Small, pub NonZeroU8);
+
+    #[typic::repr(C)]
+    #[derive(StableABI)]
+    pub struct Padded(pub u8, pub u16);
+
+    assert_eq!(TryTransmuteInto::<NonZeroU8>::try_transmute_into(7u8), Ok(NonZeroU8::new(7).unwrap()));
+    let error = TryTransmuteInto::<NonZeroU8>::try_transmute_into(0u8).unwrap_err();
+    assert_eq!((error.offset(), error.expected()), (0, Kind::Range { start: 1, end: 255 }));
+
+    assert_eq!(TryTransmuteInto::<Small>::try_transmute_into(2u8), Ok(Small::B));
+    assert_eq!(TryTransmuteInto::<Small>::try_transmute_into(3u8).unwrap_err().offset(), 0);
+
+    assert_eq!(
+        TryTransmuteInto::<Pair>::try_transmute_into([1u8, 9]),
+        Ok(Pair(Small::A, NonZeroU8::new(9).unwrap()))
+    );
+    assert_eq!(TryTransmuteInto::<Pair>::try_transmute_into([0u8, 9]).unwrap_err().offset(), 0);
+    assert_eq!(TryTransmuteInto::<Pair>::try_transmute_into([2u8, 0]).unwrap_err().offset(), 1);
+
+    // Signed discriminants are expected as signed values:
+    assert_eq!(TryTransmuteInto::<Negative>::try_transmute_into(-2i16), Ok(Negative::Two));
+    let error = TryTransmuteInto::<Negative>::try_transmute_into(2i16).unwrap_err();
+    assert_eq!(error.expected(), Kind::Value(Value::Signed(-2)));
+    assert_eq!(error.to_string(), "invalid bytes at offset 0: expected the value -2");
+
+    // Statically valid transmutations are always accepted:
+    assert_eq!(TryTransmuteInto::<u8>::try_transmute_into(Small::B), Ok(2));
+
+    // Transmutations that are invalid regardless of value are rejected:
+    assert_not_impl_any!(Padded: TryTransmuteInto<u32>);
+    assert_not_impl_any!(u16: TryTransmuteInto<u32>);
+    assert_not_impl_any!(u8: StableTransmuteInto<NonZeroU8>);
+}
+
+#[test]
+fn neglect_validity() {
+    use typic::transmute::{neglect, unsafe_transmute, UnsafeTransmuteInto};
+
+    #[typic::repr(u8)]
+    #[derive(Debug, PartialEq, StableABI)]
+    #[allow(dead_code)]
+    pub enum Small {
+        A = 1,
+        B,
+    }
+
+    #[typic::repr(u8)]
+    #[derive(StableABI)]
+    #[allow(dead_code)]
+    pub enum Large {
+        C = 3,
+    }
+
+    #[typic::repr(C)]
+    #[derive(StableABI)]
+    pub struct Padded(pub u8, pub u16);
+
+    // Narrower bytes are accepted, unchecked:
+    let x: NonZeroU8 = unsafe { unsafe_transmute::<u8, _, neglect::Validity>(7) };
+    assert_eq!(x.get(), 7);
+    let small: Small = unsafe { unsafe_transmute::<u8, _, neglect::Validity>(2) };
+    assert_eq!(small, Small::B);
+    let b: bool = unsafe { unsafe_transmute::<u8, _, (neglect::Validity,)>(1) };
+    assert!(b);
+    let c: char = unsafe { unsafe_transmute::<u32, _, neglect::Validity>(0x41) };
+    assert_eq!(c, 'A');
+
+    // ...but only if validity is neglected:
+    assert_not_impl_any!(u8: UnsafeTransmuteInto<NonZeroU8, neglect::Transparency>);
+    assert_not_impl_any!(u8: UnsafeTransmuteInto<NonZeroU8, (neglect::Alignment, neglect::Transparency)>);
+
+    // Transmutations that are invalid regardless of value are still rejected:
+    assert_not_impl_any!(Small: UnsafeTransmuteInto<Large, neglect::Validity>);
+    assert_not_impl_any!(Padded: UnsafeTransmuteInto<u32, neglect::Validity>);
+    assert_not_impl_any!(u16: UnsafeTransmuteInto<u32, neglect::Validity>);
+}
+
+#[test]
+fn neglect_options() {
+    use typic::transmute::{neglect, TransmuteOptions, UnsafeTransmuteOptions};
+
+    fn same<O: UnsafeTransmuteOptions, P>()
+    where
+        P: UnsafeTransmuteOptions<
+            Alignment = O::Alignment,
+            Transparency = O::Transparency,
+            Stability = O::Stability,
+            Validity = O::Validity,
+        >,
+    {
+    }
+
+    fn safe<O: TransmuteOptions>() {}
+
+    // Options may be combined in any order...
+    same::<(neglect::Stability, neglect::Alignment), (neglect::Alignment, neglect::Stability)>();
+    same::<
+        (neglect::Validity, neglect::Transparency, neglect::Alignment),
+        (neglect::Alignment, neglect::Transparency, neglect::Validity),
+    >();
+
+    // ...repeated, nested, or left out:
+    same::<neglect::Validity, (neglect::Validity, neglect::Validity)>();
+    same::<(neglect::Alignment, (neglect::Stability,)), (neglect::Stability, neglect::Alignment)>();
+    same::<(), ((), ())>();
+
+    // Only stability may be neglected by a safe transmutation:
+    safe::<()>();
+    safe::<neglect::Stability>();
+    safe::<(neglect::Stability,)>();
+    safe::<((), neglect::Stability)>();
+    assert_not_impl_any!(neglect::Alignment: TransmuteOptions);
+    assert_not_impl_any!((neglect::Stability, neglect::Validity): TransmuteOptions);
+    assert_not_impl_any!(u8: UnsafeTransmuteOptions);
+
+    let x = 7u32;
+    let _: &u8 = unsafe {
+        typic::transmute::unsafe_transmute::<_, _, (neglect::Transparency, neglect::Alignment)>(&x)
+    };
+}
+
+#[test]
+fn bool_and_char() {
+    use typic::transmute::TryTransmuteInto;
+
+    #[typic::repr(C)]
+    #[derive(Debug, PartialEq, StableABI)]
+    pub struct Flags {
+        pub verbose: bool,
+        pub quiet: bool,
+        pub level: u16,
+    }
+
+    let _: u8 = true.transmute_into();
+    let _: [u8; 2] = [false, true].transmute_into();
+    let _: u32 = 'a'.transmute_into();
+    let _: [u8; 4] = 'a'.transmute_into();
+    let _: u32 = Flags { verbose: true, quiet: false, level: 3 }.transmute_into();
+
+    assert_not_impl_any!(u8: StableTransmuteInto<bool>);
+    assert_not_impl_any!(NonZeroU8: StableTransmuteInto<bool>);
+    assert_not_impl_any!(u32: StableTransmuteInto<char>);
+    assert_not_impl_any!(u32: StableTransmuteInto<Flags>);
+    assert_not_impl_any!([u16; 2]: StableTransmuteInto<char>);
+
+    assert_eq!(TryTransmuteInto::<bool>::try_transmute_into(1u8), Ok(true));
+    assert_eq!(TryTransmuteInto::<bool>::try_transmute_into(2u8).unwrap_err().offset(), 0);
+    assert_eq!(TryTransmuteInto::<char>::try_transmute_into(0x41u32), Ok('A'));
+    assert!(TryTransmuteInto::<char>::try_transmute_into(0xD800u32).is_err());
+    assert_eq!(
+        TryTransmuteInto::<char>::try_transmute_into(0x1F600u32.to_ne_bytes()),
+        Ok('\u{1F600}')
+    );
+
+    let flags = TryTransmuteInto::<Flags>::try_transmute_into([1u8, 0, 7, 0]);
+    assert_eq!(flags.map(|flags| flags.verbose), Ok(true));
+    let error = TryTransmuteInto::<Flags>::try_transmute_into([1u8, 2, 7, 0]).unwrap_err();
+    assert_eq!(error.to_string(), "invalid bytes at offset 1: expected a value in 0..=1");
+}
+
+#[test]
+fn ranges() {
+    use core::num::{NonZeroU16, NonZeroU32};
+    use typenum::{U0, U1, U100, U200};
+    use typic::layout::Ranged;
+    use typic::transmute::TryTransmuteInto;
+
+    #[typic::repr(transparent)]
+    #[derive(Debug, PartialEq, StableABI)]
+    pub struct Percent(pub Ranged<u8, U0, U100>);
+
+    #[typic::repr(u8)]
+    #[derive(StableABI)]
+    #[allow(dead_code)]
+    pub enum Small {
+        A = 1,
+        B,
+    }
+
+    // Ranges widen into their supersets:
+    let _: u8 = Percent(Ranged::new(50).unwrap()).transmute_into();
+    let _: Ranged<u8, U0, U200> = Percent(Ranged::new(50).unwrap()).transmute_into();
+    let _: Ranged<u8, U0, U1> = false.transmute_into();
+    let _: bool = Ranged::<u8, U0, U1>::new(1).unwrap().transmute_into();
+    let _: NonZeroU8 = Small::B.transmute_into();
+    let _: [u8; 2] = NonZeroU16::new(1).unwrap().transmute_into();
+
+    assert_not_impl_any!(u8: StableTransmuteInto<Percent>);
+    assert_not_impl_any!(Ranged<u8, U0, U200>: StableTransmuteInto<Percent>);
+    assert_not_impl_any!(Percent: StableTransmuteInto<NonZeroU8>);
+    assert_not_impl_any!([NonZeroU8; 2]: StableTransmuteInto<NonZeroU16>);
+    assert_not_impl_any!(NonZeroU16: StableTransmuteInto<[NonZeroU8; 2]>);
+
+    // ...and narrow into their subsets, with a runtime check:
+    assert_eq!(
+        TryTransmuteInto::<Percent>::try_transmute_into(100u8),
+        Ok(Percent(Ranged::new(100).unwrap()))
+    );
+    assert!(TryTransmuteInto::<Percent>::try_transmute_into(101u8).is_err());
+    assert_eq!(
+        TryTransmuteInto::<NonZeroU32>::try_transmute_into(256u32),
+        Ok(NonZeroU32::new(256).unwrap())
+    );
+    assert!(TryTransmuteInto::<NonZeroU32>::try_transmute_into(0u32).is_err());
+    assert!(TryTransmuteInto::<NonZeroU16>::try_transmute_into([0u8, 1]).is_ok());
+}
+
+#[test]
+fn options() {
+    use core::num::NonZeroU32;
+    use core::ptr::NonNull;
+    use typic::transmute::TryTransmuteInto;
+
+    // `None` is zero, so every bit pattern of these is valid:
+    let none: Option<NonZeroU32> = 0u32.transmute_into();
+    assert_eq!(none, None);
+    let some: Option<NonZeroU32> = 7u32.transmute_into();
+    assert_eq!(some, NonZeroU32::new(7));
+    let _: u32 = some.transmute_into();
+    let _: Option<NonZeroU32> = NonZeroU32::new(7).unwrap().transmute_into();
+    let _: usize = Option::<NonNull<u8>>::None.transmute_into();
+    let _: Option<NonNull<u8>> = 0usize.transmute_into();
+    assert_eq!(
+        TryTransmuteInto::<NonZeroU32>::try_transmute_into(some),
+        Ok(NonZeroU32::new(7).unwrap())
+    );
+    assert!(TryTransmuteInto::<NonZeroU32>::try_transmute_into(none).is_err());
+
+    // ...but a reference must be null, or a valid reference:
+    let x = 5u8;
+    let ptr: *const u8 = Some(&x).transmute_into();
+    assert_eq!(ptr, &x as *const u8);
+    let ptr: *const u8 = Option::<&u8>::None.transmute_into();
+    assert!(ptr.is_null());
+    let _: usize = Some(&mut 5u8).transmute_into();
+    let _: Option<&[u8; 1]> = Some(&x).transmute_into();
+    let _: Option<&u8> = Option::<&mut u8>::None.transmute_into();
+    assert_not_impl_any!(*const u8: StableTransmuteInto<Option<&'static u8>>);
+    assert_not_impl_any!(usize: StableTransmuteInto<Option<&'static u8>>);
+    assert_not_impl_any!(Option<&'static u8>: StableTransmuteInto<&'static u8>);
+    assert_not_impl_any!(Option<&'static u8>: StableTransmuteInto<Option<&'static mut u8>>);
+    assert_not_impl_any!(Option<&'static u8>: StableTransmuteInto<NonZeroUsize>);
+
+    // Function pointers may become their addresses, but not the reverse:
+    fn f() {}
+    let _: usize = (f as fn()).transmute_into();
+    let _: Option<fn()> = Some(f as fn()).transmute_into();
+    let _: usize = Some(f as fn()).transmute_into();
+    assert_not_impl_any!(usize: StableTransmuteInto<fn()>);
+    assert_not_impl_any!(usize: StableTransmuteInto<Option<fn()>>);
+    type Unary = fn(u8);
+    assert_not_impl_any!(fn() -> (): StableTransmuteInto<Unary>);
+    assert!(typic::explain::<Option<fn()>, Option<fn()>, ()>().is_transmutable());
+    assert!(!typic::explain::<fn(), Unary, ()>().is_transmutable());
+
+    // Raw pointers may be null:
+    assert_not_impl_any!(*const u8: StableTransmuteInto<NonZeroUsize>);
+    let _: usize = core::ptr::null::<u8>().transmute_into();
+}
+
+#[test]
+fn wrappers() {
+    use core::marker::{PhantomData, PhantomPinned};
+    use core::mem::ManuallyDrop;
+    use core::ptr::NonNull;
+
+    // `NonNull` is a non-null pointer:
+    let mut x = 5u8;
+    let ptr = NonNull::from(&mut x);
+    let _: usize = ptr.transmute_into();
+    let _: *mut u8 = ptr.transmute_into();
+    let _: NonZeroUsize = ptr.transmute_into();
+    assert_not_impl_any!(*mut u8: StableTransmuteInto<NonNull<u8>>);
+    assert_not_impl_any!(NonNull<u8>: StableTransmuteInto<&'static u8>);
+
+    // `ManuallyDrop` is transparent:
+    let _: u32 = ManuallyDrop::new(5u32).transmute_into();
+    let _: ManuallyDrop<[u8; 4]> = 5u32.transmute_into();
+
+    // `PhantomData` and `PhantomPinned` are 1-aligned ZSTs, so generic
+    // containers may be given a defined layout:
+    #[typic::repr(C)]
+    #[derive(StableABI)]
+    pub struct Tagged<T>(pub u32, pub PhantomData<T>, pub PhantomPinned);
+
+    let tagged: Tagged<u64> = 5u32.transmute_into();
+    let _: [u8; 4] = tagged.transmute_into();
+    let _: () = PhantomData::<u64>.transmute_into();
+    assert_eq_size!(Tagged<u64>, u32);
+    assert_eq_align!(Tagged<u64>, u32);
+}
+
+#[test]
+fn cells() {
+    use core::cell::{Cell, UnsafeCell};
+    use typic::{explain, transmute::{neglect, Location, Reason}};
+
+    // By value, or through a unique reference, cells are transparent:
+    let _: u32 = Cell::new(5u32).transmute_into();
+    let _: Cell<u32> = 5u32.transmute_into();
+    let _: UnsafeCell<[u8; 4]> = Cell::new(5u32).transmute_into();
+    let mut x = 5u32;
+    let _: &mut Cell<u32> = (&mut x).transmute_into();
+    let _: &Cell<u32> = (&mut x).transmute_into();
+    let _: &mut u32 = (&mut Cell::new(5u32)).transmute_into();
+
+    // ...but a shared reference must keep the interior mutability of its
+    // referent, byte for byte:
+    let cell = Cell::new([1u8, 2, 3, 4]);
+    let cells: &[Cell<u8>; 4] = (&cell).transmute_into();
+    cells[1].set(5);
+    assert_eq!(cell.get(), [1, 5, 3, 4]);
+    let _: &Cell<[u8; 4]> = cells.transmute_into();
+    let _: &Cell<[u8; 4]> = (&Cell::new(5u32)).transmute_into();
+    assert_not_impl_any!(&'static Cell<u32>: StableTransmuteInto<&'static u32>);
+    assert_not_impl_any!(&'static u32: StableTransmuteInto<&'static Cell<u32>>);
+    assert_not_impl_any!(&'static [Cell<u8>; 4]: StableTransmuteInto<&'static [u8; 4]>);
+    assert_not_impl_any!(&'static Cell<[u8; 4]>: StableTransmuteInto<&'static [u8; 2]>);
+
+    // Shared bytes that are interior mutable may be written, so their
+    // validity may not change:
+    assert_not_impl_any!(&'static Cell<NonZeroU8>: StableTransmuteInto<&'static Cell<u8>>);
+    let _: &Cell<NonZeroU8> = (&Cell::new(NonZeroU8::new(1).unwrap())).transmute_into();
+
+    // ...nor may that of unique bytes that become shared, interior mutable
+    // bytes:
+    use core::num::NonZeroU32;
+    assert_not_impl_any!(&'static mut NonZeroU32: StableTransmuteInto<&'static Cell<u32>>);
+    let _: &Cell<NonZeroU32> = (&mut NonZeroU32::new(1).unwrap()).transmute_into();
+    assert_eq!(
+        explain::<&mut NonZeroU32, &Cell<u32>, neglect::Stability>()
+            .divergence()
+            .map(|divergence| (divergence.reason, divergence.location)),
+        Some((Reason::Invariance, Location::Referent))
+    );
+
+    // Everything reached through a shared reference is shared:
+    assert_not_impl_any!(&'static &'static mut Cell<u32>: StableTransmuteInto<&'static &'static mut u32>);
+    assert_not_impl_any!(&'static mut &'static Cell<u32>: StableTransmuteInto<&'static mut &'static u32>);
+
+    // A reference within a cell may be replaced through a shared reference:
+    let _: &u8 = Cell::new(&5u8).transmute_into();
+    let _: &Cell<&u8> = (&Cell::new(&5u8)).transmute_into();
+    assert_not_impl_any!(&'static Cell<&'static u8>: StableTransmuteInto<&'static &'static u8>);
+    assert_not_impl_any!(&'static Cell<&'static u8>: StableTransmuteInto<&'static Cell<usize>>);
+
+    // ...so the validity of its referent may not change:
+    assert_not_impl_any!(&'static Cell<&'static NonZeroU8>: StableTransmuteInto<&'static Cell<&'static u8>>);
+    assert_not_impl_any!(&'static mut Cell<&'static NonZeroU8>: StableTransmuteInto<&'static Cell<&'static u8>>);
+    let _: &&u8 = (&mut Cell::new(&NonZeroU8::new(1).unwrap())).transmute_into();
+
+    #[typic::repr(C)]
+    #[derive(StableABI)]
+    pub struct Counter {
+        pub id: u32,
+        pub count: Cell<u32>,
+    }
+
+    #[typic::repr(C)]
+    #[derive(StableABI)]
+    pub struct Pair(pub u32, pub Cell<u32>);
+
+    let _: &[u32; 2] = (&mut Counter { id: 1, count: Cell::new(0) }).transmute_into();
+    let _: &Pair = (&Counter { id: 1, count: Cell::new(0) }).transmute_into();
+    assert_not_impl_any!(&'static Counter: StableTransmuteInto<&'static [u32; 2]>);
+
+    let divergence = explain::<&Counter, &[u32; 2], neglect::Stability>().divergence().unwrap();
+    assert_eq!(
+        (divergence.offset, divergence.reason, divergence.location),
+        (0, Reason::InteriorMutability, Location::Referent)
+    );
+    assert!(explain::<&mut Counter, &[u32; 2], neglect::Stability>().is_transmutable());
+
+    // Atomics are cells of their values:
+    use core::sync::atomic::{AtomicPtr, AtomicU32, Ordering};
+    let _: u32 = AtomicU32::new(5).transmute_into();
+    let _: AtomicU32 = 5u32.transmute_into();
+    let mut x = 5u32;
+    let atomic: &AtomicU32 = (&mut x).transmute_into();
+    atomic.store(7, Ordering::Relaxed);
+    assert_eq!(x, 7);
+    let _: &Cell<u32> = (&AtomicU32::new(5)).transmute_into();
+    let _: usize = AtomicPtr::new(&mut x).transmute_into();
+    assert_not_impl_any!(&'static u32: StableTransmuteInto<&'static AtomicU32>);
+    assert_not_impl_any!(&'static AtomicU32: StableTransmuteInto<&'static u32>);
+    assert_not_impl_any!(&'static usize: StableTransmuteInto<&'static AtomicPtr<u8>>);
+    assert_not_impl_any!(&'static AtomicPtr<u8>: StableTransmuteInto<&'static *mut u8>);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn boxes() {
+    extern crate alloc;
+    use alloc::boxed::Box;
+    use core::num::NonZeroU32;
+
+    // A `Box` may become a `Box` whose referent has the same size and
+    // alignment, or a borrow (leaking it), or its address:
+    let boxed: Box<i32> = Box::new(5u32).transmute_into();
+    assert_eq!(*boxed, 5);
+    let _: Box<NonZeroU32> = Box::new(NonZeroU32::new(1).unwrap()).transmute_into();
+    let leaked: &'static mut u32 = Box::new(5u32).transmute_into();
+    assert_eq!(*leaked, 5);
+    let _: usize = Box::new(5u32).transmute_into();
+    let _: usize = Some(Box::new(5u32)).transmute_into();
+    let _: Option<&'static u32> = Some(Box::new(5u32)).transmute_into();
+
+    // ...but it must free its referent as it was allocated:
+    assert_not_impl_any!(Box<u32>: StableTransmuteInto<Box<[u8; 4]>>);
+    assert_not_impl_any!(Box<u64>: StableTransmuteInto<Box<u32>>);
+    assert_not_impl_any!(&'static mut u32: StableTransmuteInto<Box<u32>>);
+    assert_not_impl_any!(Option<&'static mut u32>: StableTransmuteInto<Option<Box<u32>>>);
+    assert!(!typic::explain::<Box<u32>, Box<[u8; 4]>, ()>().is_transmutable());
+    assert!(!typic::explain::<&'static mut u32, Box<u32>, ()>().is_transmutable());
+}
+
+#[test]
+fn maybe_uninit() {
+    use core::mem::MaybeUninit;
+
+    #[typic::repr(C)]
+    #[derive(StableABI)]
+    pub struct Padded(pub u8, pub u16);
+
+    // Any bytes may become uninitialized bytes of equal or smaller size...
+    let _: MaybeUninit<u32> = 5u32.transmute_into();
+    let _: MaybeUninit<u16> = 5u32.transmute_into();
+    let _: MaybeUninit<[u8; 4]> = Padded(1, 2).transmute_into();
+    let _: MaybeUninit<Padded> = MaybeUninit::<u32>::uninit().transmute_into();
+    assert_not_impl_any!(u16: StableTransmuteInto<MaybeUninit<u32>>);
+
+    // ...but uninitialized bytes may only become uninitialized bytes:
+    assert_not_impl_any!(MaybeUninit<u32>: StableTransmuteInto<u32>);
+    assert_not_impl_any!(MaybeUninit<u8>: StableTransmuteInto<bool>);
+
+    // Arrays of uninitialized elements are uninitialized arrays:
+    let buffer: [MaybeUninit<Padded>; 4] = MaybeUninit::<[Padded; 4]>::uninit().transmute_into();
+    let _: MaybeUninit<[Padded; 4]> = buffer.transmute_into();
+    assert_not_impl_any!(&'static mut [u8; 4]: StableTransmuteInto<&'static mut [MaybeUninit<u8>; 4]>);
+}
+
+#[test]
+fn long_arrays() {
+    #[typic::repr(C)]
+    #[derive(StableABI)]
+    pub struct Frame {
+        pub header: [u8; 64],
+        pub payload: [u8; 1500],
+        pub table: [u32; 256],
+    }
+
+    let frame: Frame = [0u8; 2588].transmute_into();
+    let _: [u16; 1294] = frame.transmute_into();
+
+    let page: [u64; 512] = [1u8; 4096].transmute_into();
+    let _: &[u8; 4096] = (&page).transmute_into();
+    let _: [u8; 1 << 16] = [0u32; 1 << 14].transmute_into();
+
+    assert_not_impl_any!([u8; 4095]: StableTransmuteInto<[u8; 4096]>);
+    assert_not_impl_any!(&'static [u8; 4096]: StableTransmuteInto<&'static [u64; 512]>);
+    assert_not_impl_any!([u8; 16]: StableTransmuteInto<[bool; 16]>);
+    let _: [u8; 16] = [false; 16].transmute_into();
+
+    // Lengths are the same type-level numbers as typenum's:
+    use typic::private::num::{Add1, Length, U1024, U4096, U65536};
+    assert_type_eq_all!(Length<1024>, U1024);
+    assert_type_eq_all!(Length<1025>, Add1<U1024>);
+    assert_type_eq_all!(Length<4096>, U4096);
+    assert_type_eq_all!(Length<{ 1 << 16 }>, U65536);
+    #[cfg(target_pointer_width = "64")]
+    assert_type_eq_all!(Length<{ 1 << 32 }>, typic::private::num::U4294967296);
+}
+
+#[test]
+fn slices() {
+    use typic::extras::bytemuck::try_cast_slice;
+    use typic::transmute::{SliceError, TransmuteSliceInto, TransmuteSlicePrefixInto};
+
+    #[typic::repr(C)]
+    #[derive(Debug, PartialEq, StableABI)]
+    pub struct Pixel(pub u8, pub u8, pub u8, pub u8);
+
+    // Elements may be split...
+    let words = [1u32, 2];
+    let bytes = TransmuteSliceInto::<&[u8]>::transmute_slice_into(&words[..]).unwrap();
+    assert_eq!(bytes.len(), 8);
+    let halves = TransmuteSliceInto::<&[[u8; 2]]>::transmute_slice_into(&words[..]).unwrap();
+    assert_eq!(halves.len(), 4);
+
+    // ...or joined:
+    let bytes = [1u8, 2, 3, 4, 5, 6, 7, 8];
+    let pixels = TransmuteSliceInto::<&[Pixel]>::transmute_slice_into(&bytes[..]).unwrap();
+    assert_eq!(pixels, &[Pixel(1, 2, 3, 4), Pixel(5, 6, 7, 8)]);
+    assert_eq!(
+        TransmuteSliceInto::<&[Pixel]>::transmute_slice_into(&bytes[..7]),
+        Err(SliceError::Size { size: 7, element: 4 })
+    );
+    let (pixels, rest) =
+        TransmuteSlicePrefixInto::<&[Pixel]>::transmute_slice_prefix_into(&bytes[..7]).unwrap();
+    assert_eq!(pixels, &[Pixel(1, 2, 3, 4)]);
+    assert_eq!(rest, &[5, 6, 7]);
+
+    // Shared elements may be widened:
+    let nonzero = [core::num::NonZeroU32::new(1).unwrap(); 2];
+    let words = TransmuteSliceInto::<&[u32]>::transmute_slice_into(&nonzero[..]).unwrap();
+    assert_eq!(words, &[1, 1]);
+
+    // ...unless they become interior mutable:
+    use core::{cell::Cell, num::NonZeroU8};
+    assert_not_impl_any!(&'static mut [NonZeroU8]: TransmuteSliceInto<&'static [Cell<u8>]>);
+
+    // The alignment of the destination is checked at runtime:
+    let words = [0u32; 2];
+    let bytes = TransmuteSliceInto::<&[u8]>::transmute_slice_into(&words[..]).unwrap();
+    assert!(TransmuteSliceInto::<&[u32]>::transmute_slice_into(&bytes[..4]).is_ok());
+    assert!(matches!(
+        TransmuteSliceInto::<&[u32]>::transmute_slice_into(&bytes[1..5]),
+        Err(SliceError::Misaligned { align: 4, .. })
+    ));
+    assert_eq!(try_cast_slice::<u8, u16>(&bytes[..2]), Some(&[0u16][..]));
+    assert_eq!(try_cast_slice::<u8, u16>(&bytes[..3]), None);
+
+    // Mutable slices stay mutable, or become shared:
+    let mut bytes = [0u8; 4];
+    let pixels = TransmuteSliceInto::<&mut [Pixel]>::transmute_slice_into(&mut bytes[..]).unwrap();
+    pixels[0].0 = 1;
+    let (chunks, rest) =
+        TransmuteSlicePrefixInto::<&mut [[u8; 3]]>::transmute_slice_prefix_into(&mut bytes[..]).unwrap();
+    chunks[0][1] = 2;
+    rest[0] = 4;
+    assert_eq!(bytes, [1, 2, 0, 4]);
+    let _ = TransmuteSliceInto::<&[u8]>::transmute_slice_into(&mut bytes[..]).unwrap();
+
+    // Strings are slices of bytes:
+    let pairs = TransmuteSliceInto::<&[[u8; 2]]>::transmute_slice_into("abcd").unwrap();
+    assert_eq!(pairs, &[*b"ab", *b"cd"]);
+
+    // Element layouts are checked statically:
+    assert_not_impl_any!(&'static [u8]: TransmuteSliceInto<&'static [bool]>);
+    assert_not_impl_any!(&'static [u8]: TransmuteSliceInto<&'static mut [u8]>);
+    assert_not_impl_any!(&'static mut [u8]: TransmuteSliceInto<&'static mut [NonZeroU8]>);
+    assert_not_impl_any!(&'static [u16]: TransmuteSliceInto<&'static [[u8; 3]]>);
+    assert_not_impl_any!(&'static [()]: TransmuteSliceInto<&'static [u8]>);
+    assert_not_impl_any!(&'static str: TransmuteSliceInto<&'static [char]>);
+}
+
+#[test]
+fn zerocopy_refs() {
+    use typic::extras::zerocopy::Ref;
+    use typic::transmute::TransmuteSliceInto;
+
+    #[typic::repr(C)]
+    #[derive(Debug, PartialEq, StableABI)]
+    pub struct Header {
+        pub kind: u16,
+        pub flags: u16,
+        pub len: u32,
+    }
+
+    #[typic::repr(C)]
+    #[derive(StableABI)]
+    pub struct Padded(pub u8, pub u16);
+
+    let mut words = [0u32; 4];
+    let bytes = TransmuteSliceInto::<&mut [u8]>::transmute_slice_into(&mut words[..]).unwrap();
+    bytes[..8].copy_from_slice(&[1, 0, 0, 0, 8, 0, 0, 0]);
+
+    // Lengths and alignments are checked at runtime:
+    assert!(Ref::<&[u8], Header>::new(&bytes[..8]).is_some());
+    assert!(Ref::<&[u8], Header>::new(&bytes[..9]).is_none());
+    assert!(Ref::<&[u8], Header>::new(&bytes[2..10]).is_none());
+    assert!(Ref::<&[u8], Header>::new_prefix(&bytes[..7]).is_none());
+    assert!(Ref::<&[u8], Header>::new_suffix(&bytes[..15]).is_none());
+    assert!(Ref::<&[u8], [u32]>::new_slice(&bytes[..6]).is_none());
+    assert!(Ref::<&[u8], [u32]>::new_slice(&bytes[1..9]).is_none());
+
+    // ...and the rest of the bytes are handed back:
+    let (header, rest) = Ref::<&[u8], Header>::new_prefix(&bytes[..]).unwrap();
+    assert_eq!(rest.len(), 8);
+    let header = header.into_ref();
+    assert_eq!((header.kind, header.len), (u16::from_ne_bytes([1, 0]), u32::from_ne_bytes([8, 0, 0, 0])));
+    let (rest, words) = Ref::<&[u8], [u32; 2]>::new_suffix(&bytes[..]).unwrap();
+    assert_eq!((rest.len(), *words), (8, [0, 0]));
+    let words = Ref::<&[u8], [u32]>::new_slice(&bytes[..]).unwrap();
+    assert_eq!(words.len(), 4);
+
+    // Types without alignment requirements may be viewed at any offset:
+    let pair = Ref::<&[u8], [u8; 2]>::new_unaligned(&bytes[3..5]).unwrap();
+    assert_eq!(*pair, [0, 8]);
+
+    // Mutable views update the bytes in place:
+    let (mut header, body) = Ref::<&mut [u8], Header>::new_prefix(&mut bytes[..]).unwrap();
+    header.flags = 0xFFFF;
+    body[0] = 7;
+    let mut words = Ref::<&mut [u8], [u16]>::new_slice(body).unwrap();
+    words[1] = 0xFFFF;
+    assert_eq!(&bytes[..12], &[1, 0, 0xFF, 0xFF, 8, 0, 0, 0, 7, 0, 0xFF, 0xFF]);
+
+    // Views are only dereferenceable if every byte pattern is a valid
+    // referent, and mutably, if every referent is a valid byte pattern:
+    assert_impl_all!(Ref<&'static mut [u8], Padded>: core::ops::Deref);
+    assert_not_impl_any!(Ref<&'static mut [u8], Padded>: core::ops::DerefMut);
+    assert_not_impl_any!(Ref<&'static [u8], NonZeroU8>: core::ops::Deref);
+    assert_not_impl_any!(Ref<&'static [u8], u32>: core::ops::DerefMut);
+
+    // A shared view may not be written through, so it may not be interior
+    // mutable:
+    use core::cell::Cell;
+    use core::sync::atomic::AtomicU32;
+    assert_not_impl_any!(Ref<&'static [u8], Cell<u32>>: core::ops::Deref);
+    assert_not_impl_any!(Ref<&'static [u8], AtomicU32>: core::ops::Deref);
+    assert_not_impl_any!(Ref<&'static [u8], [Cell<u8>]>: core::ops::Deref);
+    assert_not_impl_any!(Ref<&'static [u8], [AtomicU32]>: core::ops::Deref);
+    assert_not_impl_any!(Ref<&'static mut [u8], AtomicU32>: core::ops::Deref);
+
+    // ...though the bytes of a unique view may be borrowed as such:
+    let mut words = [0u32; 1];
+    let bytes = TransmuteSliceInto::<&mut [u8]>::transmute_slice_into(&mut words[..]).unwrap();
+    let atomic = Ref::<&mut [u8], AtomicU32>::new(bytes).unwrap().into_mut();
+    atomic.store(7, core::sync::atomic::Ordering::Relaxed);
+    assert_eq!(words, [7]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn boxed_slices() {
+    extern crate alloc;
+    use alloc::{boxed::Box, vec};
+    use typic::transmute::TransmuteSliceInto;
+
+    let words: Box<[i32]> =
+        TransmuteSliceInto::<Box<[i32]>>::transmute_slice_into(vec![1u32, 2].into_boxed_slice()).unwrap();
+    assert_eq!(&*words, &[1, 2]);
+    let leaked = TransmuteSliceInto::<&'static [u8]>::transmute_slice_into(words).unwrap();
+    assert_eq!(leaked.len(), 8);
+
+    // A `Box` frees its elements as they were allocated:
+    assert_not_impl_any!(Box<[u32]>: TransmuteSliceInto<Box<[u8]>>);
+    assert_not_impl_any!(Box<[u8]>: TransmuteSliceInto<Box<[u16]>>);
+}
+
+#[test]
+fn byteorder() {
+    use typic::byteorder::*;
+    use typic::transmute::TryTransmuteInto;
+
+    #[typic::repr(C)]
+    #[derive(Debug, StableABI)]
+    pub struct Header {
+        pub version: u8,
+        pub kind: U16BE,
+        pub len: U32LE,
+        pub id: NonZeroU64BE,
+    }
+
+    assert_eq_size!(Header, [u8; 15]);
+    assert_eq_align!(Header, u8);
+
+    let bytes = [1u8, 0, 2, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4];
+    let header = TryTransmuteInto::<Header>::try_transmute_into(bytes).unwrap();
+    assert_eq!(header.version, 1);
+    assert_eq!(header.kind.get(), 2);
+    assert_eq!(header.len.get(), 3);
+    assert_eq!(header.id.get().get(), 4);
+    let _: [u8; 15] = header.transmute_into();
+
+    let error = TryTransmuteInto::<Header>::try_transmute_into([0u8; 15]).unwrap_err();
+    assert_eq!(error.offset(), 7);
+    assert_not_impl_any!([u8; 15]: StableTransmuteInto<Header>);
+
+    // Every bit pattern of the others is valid:
+    let mut value: I32BE = [0xFFu8, 0xFF, 0xFF, 0xFE].transmute_into();
+    assert_eq!(value.get(), -2);
+    value.set(1);
+    let bytes: [u8; 4] = value.transmute_into();
+    assert_eq!(bytes, [0, 0, 0, 1]);
+    assert_eq!(U16LE::from(0x0102).get(), 0x0102);
+    let _: &[U16BE; 2] = (&[0u8; 4]).transmute_into();
+    assert_not_impl_any!(&'static [u8; 2]: StableTransmuteInto<&'static NonZeroU16LE>);
+}
+
+#[test]
+fn targets() {
+    use typic::{assert_transmutable_on, transmute::neglect};
+    use typic::target::{Aarch64, Armv7, I686, Powerpc64, Wasm32, X86_64};
+
+    #[typic::repr(C)]
+    #[derive(StableABI)]
+    pub struct Packet {
+        pub len: u32,
+        pub seq: u64,
+    }
+
+    assert_transmutable_on!(Aarch64, Armv7, Powerpc64, Wasm32, X86_64; [u8; 16] => Packet);
+    assert_transmutable_on!(I686; [u8; 12] => Packet);
+    assert_transmutable_on!(I686; Packet => [u32; 3]);
+
+    #[typic::repr(C)]
+    #[derive(StableABI)]
+    pub struct Wide(pub u32, pub u128);
+
+    assert_transmutable_on!(Armv7; [u8; 24] => Wide);
+    assert_transmutable_on!(Aarch64, I686, X86_64; [u8; 32] => Wide);
+
+    assert_transmutable_on!(Armv7, I686, Wasm32; usize => [u8; 4], neglect::Stability);
+    assert_transmutable_on!(Aarch64, Powerpc64, X86_64; usize => [u8; 8], neglect::Stability);
+    assert_transmutable_on!(Wasm32; &'static [u8; 4] => &'static [u8; 2]);
+    assert_transmutable_on!(Wasm32; Option<&'static u8> => u32, neglect::Stability);
+    assert_transmutable_on!(X86_64; Option<&'static u8> => u64, neglect::Stability);
+
+    // The discriminants of pointer-sized enums are those of the target:
+    #[typic::repr(isize)]
+    #[derive(StableABI)]
+    #[allow(dead_code)]
+    pub enum Pointer {
+        N = -1,
+    }
+
+    #[typic::repr(u32)]
+    #[derive(StableABI)]
+    #[allow(dead_code)]
+    pub enum Word {
+        N = 0xFFFF_FFFF,
+    }
+
+    #[typic::repr(u64)]
+    #[derive(StableABI)]
+    #[allow(dead_code)]
+    pub enum Long {
+        N = 0xFFFF_FFFF_FFFF_FFFF,
+    }
+
+    assert_transmutable_on!(Armv7, I686, Wasm32; Pointer => Word);
+    assert_transmutable_on!(Armv7, I686, Wasm32; Word => Pointer);
+    assert_transmutable_on!(Aarch64, Powerpc64, X86_64; Pointer => Long);
+    assert_transmutable_on!(Aarch64, Powerpc64, X86_64; Long => Pointer);
 }
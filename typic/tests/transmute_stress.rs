@@ -1,10 +1,10 @@
 #![recursion_limit = "512"]
 
-use static_assertions::*;
 use typic::{self, stability::StableABI, transmute::StableTransmuteInto};
 
 // Adapted From:
 // https://rust-lang.zulipchat.com/#narrow/stream/216762-project-safe-transmute/topic/typic/near/185459723
+#[test]
 fn stress() {
     #[typic::repr(C)]
     #[derive(Default, StableABI)]